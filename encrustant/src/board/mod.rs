@@ -24,6 +24,7 @@ use square::Square;
 use self::game_state::GameState;
 
 /// Represents a chess position.
+#[derive(Clone)]
 pub struct Board {
     /// Whether it is white's turn to move.
     pub white_to_move: bool,
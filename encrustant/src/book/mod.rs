@@ -0,0 +1,264 @@
+//! Polyglot opening books.
+
+mod polyglot_key;
+
+use core::hash::{BuildHasher, Hasher};
+use std::{collections::hash_map::RandomState, fs, io, path::Path};
+
+pub use polyglot_key::polyglot_key;
+
+use crate::{
+    board::{Board, square::Square},
+    move_generator::{
+        MoveGenerator,
+        move_data::{Flag, Move},
+    },
+};
+
+/// Size of an entry in a Polyglot book file.
+const ENTRY_SIZE: usize = 16;
+
+/// A move for a position in the book.
+#[derive(Clone, Copy)]
+struct Entry {
+    /// Polyglot key of the position.
+    key: u64,
+
+    /// Move in Polyglot encoding.
+    polyglot_move: u16,
+
+    /// How often the move should be played compared to others for the same position.
+    weight: u16,
+}
+
+/// How a move is chosen when a position has more than one book move.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BookSelection {
+    /// Randomly, more often the higher the weight of the move.
+    Weighted,
+
+    /// Always the move with the highest weight.
+    Best,
+}
+
+/// Opening book read from a Polyglot `.bin` file.
+pub struct Book {
+    /// Entries sorted by key.
+    entries: Vec<Entry>,
+}
+
+impl Book {
+    /// Reads a Polyglot book from a file.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file could not be read.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::from_bytes(&fs::read(path)?))
+    }
+
+    /// Reads a Polyglot book from its bytes. A partial entry at the end is ignored.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut entries: Vec<Entry> = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|chunk| Entry {
+                key: read_big_endian(&chunk[0..8]),
+                polyglot_move: read_big_endian(&chunk[8..10]) as u16,
+                weight: read_big_endian(&chunk[10..12]) as u16,
+            })
+            .collect();
+
+        // Books should already be sorted, but binary search relies on it
+        entries.sort_by_key(|entry| entry.key);
+
+        Self { entries }
+    }
+
+    /// Returns how many entries the book has.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the book has no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the legal book moves for `board` with their weights.
+    #[must_use]
+    pub fn moves(&self, board: &Board) -> Vec<(Move, u16)> {
+        let key = polyglot_key(board);
+        let start = self.entries.partition_point(|entry| entry.key < key);
+
+        let mut legal_moves = Vec::new();
+        MoveGenerator::new(board).generate(|move_data| legal_moves.push(move_data), false);
+
+        self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.key == key)
+            .filter_map(|entry| {
+                let move_data = legal_moves
+                    .iter()
+                    .find(|move_data| matches_polyglot_move(**move_data, entry.polyglot_move))?;
+                Some((*move_data, entry.weight))
+            })
+            .collect()
+    }
+
+    /// Picks a book move for `board`, or returns `None` if the position is not in the book.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn probe(&self, board: &Board, selection: BookSelection) -> Option<Move> {
+        let moves = self.moves(board);
+
+        match selection {
+            BookSelection::Best => moves
+                .iter()
+                .max_by_key(|(_, weight)| *weight)
+                .map(|(move_data, _)| *move_data),
+            BookSelection::Weighted => {
+                let total_weight: u64 = moves.iter().map(|(_, weight)| u64::from(*weight)).sum();
+                if total_weight == 0 {
+                    // Every move has no weight, so choose one evenly
+                    return moves
+                        .get(random_below(moves.len() as u64) as usize)
+                        .map(|(move_data, _)| *move_data);
+                }
+
+                let mut chosen = random_below(total_weight);
+                for (move_data, weight) in moves {
+                    let weight = u64::from(weight);
+                    if chosen < weight {
+                        return Some(move_data);
+                    }
+                    chosen -= weight;
+                }
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// Reads an unsigned big-endian integer, as every number in Polyglot books is stored.
+fn read_big_endian(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 8) | u64::from(*byte))
+}
+
+/// Returns a random integer less than `bound`, or 0 if `bound` is 0.
+fn random_below(bound: u64) -> u64 {
+    if bound == 0 {
+        return 0;
+    }
+
+    // Every `RandomState` is seeded differently
+    RandomState::new().build_hasher().finish() % bound
+}
+
+/// Returns whether `move_data` is the Polyglot encoded move `polyglot_move`.
+fn matches_polyglot_move(move_data: Move, polyglot_move: u16) -> bool {
+    #[allow(clippy::cast_possible_truncation)]
+    let square = |bits: u16| Square::from_coords(((bits >> 3) & 7) as i8, (bits & 7) as i8);
+
+    let to = square(polyglot_move);
+    let from = square(polyglot_move >> 6);
+    let promotion = match (polyglot_move >> 12) & 7 {
+        1 => Flag::KnightPromotion,
+        2 => Flag::BishopPromotion,
+        3 => Flag::RookPromotion,
+        4 => Flag::QueenPromotion,
+        _ => Flag::None,
+    };
+
+    if move_data.from != from {
+        return false;
+    }
+
+    match move_data.flag {
+        // Castling is stored as the king capturing its own rook
+        Flag::Castle => {
+            to == move_data
+                .to
+                .offset(if move_data.to.file() == 6 { 1 } else { -2 })
+        }
+        Flag::QueenPromotion
+        | Flag::RookPromotion
+        | Flag::BishopPromotion
+        | Flag::KnightPromotion => move_data.to == to && move_data.flag == promotion,
+        Flag::None | Flag::EnPassant | Flag::PawnTwoUp => {
+            move_data.to == to && promotion == Flag::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{Board, square::Square},
+        book::{Book, BookSelection, polyglot_key},
+        move_generator::move_data::{Flag, Move},
+    };
+
+    fn entry(board: &Board, from: &str, to: &str, weight: u16) -> [u8; 16] {
+        let (from, to) = (
+            Square::from_notation(from).unwrap(),
+            Square::from_notation(to).unwrap(),
+        );
+        #[allow(clippy::cast_sign_loss)]
+        let polyglot_move = (to.file() as u16)
+            | (to.rank() as u16) << 3
+            | (from.file() as u16) << 6
+            | (from.rank() as u16) << 9;
+
+        let mut bytes = [0; 16];
+        bytes[0..8].copy_from_slice(&polyglot_key(board).to_be_bytes());
+        bytes[8..10].copy_from_slice(&polyglot_move.to_be_bytes());
+        bytes[10..12].copy_from_slice(&weight.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn book_moves_are_found() {
+        let start = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+        let castling =
+            Board::from_fen("r3k2r/pppq1ppp/2npbn2/2b1p3/2B1P3/2NPBN2/PPPQ1PPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+
+        let bytes = [
+            entry(&castling, "e1", "h1", 5),
+            entry(&start, "d2", "d4", 1),
+            entry(&start, "e2", "e4", 3),
+            // Not legal, so ignored
+            entry(&start, "e2", "e5", 100),
+        ]
+        .concat();
+        let book = Book::from_bytes(&bytes);
+        assert_eq!(book.len(), 4);
+
+        let e4 = Move {
+            from: Square::from_notation("e2").unwrap(),
+            to: Square::from_notation("e4").unwrap(),
+            flag: Flag::PawnTwoUp,
+        };
+        assert_eq!(book.moves(&start).len(), 2);
+        assert!(book.probe(&start, BookSelection::Best) == Some(e4));
+        assert!(book.probe(&start, BookSelection::Weighted).is_some());
+
+        assert!(
+            book.probe(&castling, BookSelection::Weighted)
+                == Some(Move {
+                    from: Square::from_notation("e1").unwrap(),
+                    to: Square::from_notation("g1").unwrap(),
+                    flag: Flag::Castle,
+                })
+        );
+
+        let not_in_book = Board::from_fen("8/8/8/4k3/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(book.probe(&not_in_book, BookSelection::Best).is_none());
+    }
+}
@@ -0,0 +1,468 @@
+//! Knowledge of endgames the tapered evaluation gets wrong, looked up by material signature.
+
+use crate::{
+    board::{Board, bit_board::BitBoard, piece::Piece, square::Square},
+    move_generator::static_exchange::SEE_PIECE_VALUES,
+};
+
+use super::{eval_data::EvalNumber, kpk, terms::file_bit_board};
+
+/// Scale factor that leaves the evaluation unchanged.
+pub const SCALE_NORMAL: EvalNumber = 64;
+
+/// Added to the score of endgames that are won with correct play, so they are preferred to any other evaluation.
+pub const KNOWN_WIN: EvalNumber = 10000;
+
+/// Counts of pawns, knights, bishops, rooks and queens, of white then black.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MaterialSignature([[u8; 5]; 2]);
+
+impl MaterialSignature {
+    /// Counts the material on the board.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // There are never more than 64 pieces
+    pub fn new(board: &Board) -> Self {
+        let count = |pieces: [Piece; 6]| {
+            let mut counts = [0; 5];
+            for (count, piece) in counts.iter_mut().zip(pieces) {
+                *count = board.get_bit_board(piece).count() as u8;
+            }
+            counts
+        };
+        Self([count(Piece::WHITE_PIECES), count(Piece::BLACK_PIECES)])
+    }
+
+    /// Parses a signature like `KBNK`: the pieces of white, then the pieces of black, each starting with the king.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the code has a letter other than `KQRBNP`, or does not have two kings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use encrustant::{board::Board, evaluation::endgame::MaterialSignature};
+    ///
+    /// let board = Board::from_fen("8/8/8/4k3/8/8/8/KBN5 w - - 0 1").unwrap();
+    /// assert_eq!(MaterialSignature::new(&board), MaterialSignature::from_code("KBNK"));
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: &str) -> Self {
+        let code = code.as_bytes();
+        let mut counts = [[0; 5]; 2];
+        let mut kings = 0;
+
+        let mut index = 0;
+        while index < code.len() {
+            let piece = match code[index] {
+                b'K' => {
+                    kings += 1;
+                    index += 1;
+                    continue;
+                }
+                b'P' => 0,
+                b'N' => 1,
+                b'B' => 2,
+                b'R' => 3,
+                b'Q' => 4,
+                _ => panic!("Unknown piece in material signature"),
+            };
+            assert!(kings == 1 || kings == 2, "Pieces must follow a king");
+            counts[kings - 1][piece] += 1;
+            index += 1;
+        }
+        assert!(kings == 2, "Material signature needs two kings");
+
+        Self(counts)
+    }
+
+    /// Returns the same material with the colours swapped.
+    #[must_use]
+    pub const fn flip(self) -> Self {
+        Self([self.0[1], self.0[0]])
+    }
+
+    /// Returns whether a side only has a king.
+    const fn is_bare_king(self, white: bool) -> bool {
+        let counts = self.0[if white { 0 } else { 1 }];
+        counts[0] == 0 && counts[1] == 0 && counts[2] == 0 && counts[3] == 0 && counts[4] == 0
+    }
+
+    /// Returns the value of a side's pieces other than pawns and the king.
+    fn non_pawn_material(self, white: bool) -> EvalNumber {
+        let counts = self.0[usize::from(!white)];
+        (1..5)
+            .map(|piece| EvalNumber::from(counts[piece]) * SEE_PIECE_VALUES[piece])
+            .sum()
+    }
+}
+
+/// What is known about the endgame of a position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Endgame {
+    /// Score of the position from white's perspective, replacing the evaluation.
+    Score(EvalNumber),
+
+    /// Factor out of [`SCALE_NORMAL`] to scale the evaluation by when it favours white, then when it favours black.
+    Scale([EvalNumber; 2]),
+}
+
+/// Knowledge of an endgame for a side with the stronger material.
+#[derive(Clone, Copy)]
+enum Specialised {
+    /// Returns the score of the position for the strong side.
+    Evaluation(fn(&Board, bool) -> EvalNumber),
+
+    /// Returns the score of the position for the strong side, or `None` to fall back to the evaluation.
+    Lookup(fn(&Board, bool) -> Option<EvalNumber>),
+
+    /// Returns the factor to scale the evaluation by when it favours the strong side.
+    Scale(fn(&Board, bool) -> EvalNumber),
+}
+
+/// Endgames with specialised knowledge, with white as the strong side.
+const ENDGAMES: [(MaterialSignature, Specialised); 4] = [
+    (
+        MaterialSignature::from_code("KPK"),
+        Specialised::Lookup(king_pawn_king),
+    ),
+    (
+        MaterialSignature::from_code("KBNK"),
+        Specialised::Evaluation(king_bishop_knight_king),
+    ),
+    (
+        MaterialSignature::from_code("KQKR"),
+        Specialised::Evaluation(king_queen_king_rook),
+    ),
+    (
+        MaterialSignature::from_code("KRKB"),
+        Specialised::Scale(king_rook_king_bishop),
+    ),
+];
+
+const fn king_square(board: &Board, white: bool) -> Square {
+    board
+        .get_bit_board(if white {
+            Piece::WhiteKing
+        } else {
+            Piece::BlackKing
+        })
+        .first_square()
+}
+
+const fn distance(a: Square, b: Square) -> EvalNumber {
+    let rank_distance = (a.rank() - b.rank()).abs();
+    let file_distance = (a.file() - b.file()).abs();
+    (if rank_distance > file_distance {
+        rank_distance
+    } else {
+        file_distance
+    }) as EvalNumber
+}
+
+/// Bonus for the weak king being near the edge of the board.
+fn push_to_edge(square: Square) -> EvalNumber {
+    let rank_from_edge = EvalNumber::from(square.rank().min(7 - square.rank()));
+    let file_from_edge = EvalNumber::from(square.file().min(7 - square.file()));
+    90 - 7 * (rank_from_edge * rank_from_edge + file_from_edge * file_from_edge) / 2
+}
+
+/// Bonus for the kings being close to each other.
+const fn push_close(a: Square, b: Square) -> EvalNumber {
+    140 - 20 * distance(a, b)
+}
+
+/// Bonus for the square being near a1 or h8.
+fn push_to_dark_corner(square: Square) -> EvalNumber {
+    EvalNumber::from((7 - square.rank() - square.file()).abs())
+}
+
+/// Mates a bare king with a bishop and knight, which can only be done in a corner of the bishop's colour.
+fn king_bishop_knight_king(board: &Board, strong_white: bool) -> EvalNumber {
+    let strong_king = king_square(board, strong_white);
+    let weak_king = king_square(board, !strong_white);
+    let bishop = if strong_white {
+        Piece::WhiteBishop
+    } else {
+        Piece::BlackBishop
+    };
+
+    // Flipping the file swaps a light corner with a dark one
+    let corner_square = if board
+        .get_bit_board(bishop)
+        .overlaps(&BitBoard::DARK_SQUARES)
+    {
+        weak_king
+    } else {
+        Square::from_coords(weak_king.rank(), 7 - weak_king.file())
+    };
+
+    KNOWN_WIN + push_close(strong_king, weak_king) + 50 * push_to_dark_corner(corner_square)
+}
+
+/// Looks up king and pawn against king in the bitbase, preferring wins with the pawn further up the board.
+///
+/// Returns `None` for a pawn on the first or last rank, which the bitbase does not have.
+fn king_pawn_king(board: &Board, strong_white: bool) -> Option<EvalNumber> {
+    // The bitbase has white as the strong side
+    let relative = |square: Square| if strong_white { square } else { square.flip() };
+    let pawn = relative(
+        board
+            .get_bit_board(if strong_white {
+                Piece::WhitePawn
+            } else {
+                Piece::BlackPawn
+            })
+            .first_square(),
+    );
+
+    let wins = kpk::probe(
+        board.white_to_move == strong_white,
+        relative(king_square(board, strong_white)),
+        relative(king_square(board, !strong_white)),
+        pawn,
+    )?;
+    Some(if wins {
+        KNOWN_WIN + SEE_PIECE_VALUES[0] + 20 * EvalNumber::from(pawn.rank())
+    } else {
+        0
+    })
+}
+
+/// Queen against rook is won by driving the king to the edge, where the rook gets lost.
+fn king_queen_king_rook(board: &Board, strong_white: bool) -> EvalNumber {
+    let strong_king = king_square(board, strong_white);
+    let weak_king = king_square(board, !strong_white);
+
+    SEE_PIECE_VALUES[4] - SEE_PIECE_VALUES[3]
+        + push_to_edge(weak_king)
+        + push_close(strong_king, weak_king)
+}
+
+/// Rook against bishop is usually a draw.
+const fn king_rook_king_bishop(_board: &Board, _strong_white: bool) -> EvalNumber {
+    SCALE_NORMAL / 8
+}
+
+/// Mates a bare king with enough material by driving it to the edge.
+fn king_and_material_king(
+    board: &Board,
+    signature: MaterialSignature,
+    strong_white: bool,
+) -> EvalNumber {
+    let strong_king = king_square(board, strong_white);
+    let weak_king = king_square(board, !strong_white);
+    let counts = signature.0[usize::from(!strong_white)];
+
+    let mut score = signature.non_pawn_material(strong_white)
+        + EvalNumber::from(counts[0]) * SEE_PIECE_VALUES[0]
+        + push_to_edge(weak_king)
+        + push_close(strong_king, weak_king);
+
+    let bishops = *board.get_bit_board(if strong_white {
+        Piece::WhiteBishop
+    } else {
+        Piece::BlackBishop
+    });
+    let can_force_mate = counts[4] > 0
+        || counts[3] > 0
+        || (counts[1] > 0 && counts[2] > 0)
+        || (bishops.overlaps(&BitBoard::LIGHT_SQUARES)
+            && bishops.overlaps(&BitBoard::DARK_SQUARES));
+    if can_force_mate {
+        score += KNOWN_WIN;
+    }
+    score
+}
+
+/// Returns whether the only pieces other than kings and pawns are one bishop each, on opposite colours.
+fn is_opposite_coloured_bishops(board: &Board, signature: MaterialSignature) -> bool {
+    let [white, black] = signature.0;
+    if white[1..] != [0, 1, 0, 0] || black[1..] != [0, 1, 0, 0] {
+        return false;
+    }
+
+    let white_bishop = *board.get_bit_board(Piece::WhiteBishop);
+    let black_bishop = *board.get_bit_board(Piece::BlackBishop);
+    white_bishop.overlaps(&BitBoard::LIGHT_SQUARES)
+        != black_bishop.overlaps(&BitBoard::LIGHT_SQUARES)
+}
+
+/// Returns whether the strong side has only a bishop and pawns on one rook file, the bishop does not control the
+/// promotion square, and the bare weak king is already near it.
+fn is_wrong_bishop_rook_pawn(
+    board: &Board,
+    signature: MaterialSignature,
+    strong_white: bool,
+) -> bool {
+    let counts = signature.0[usize::from(!strong_white)];
+    if counts[0] == 0 || counts[1..] != [0, 1, 0, 0] || !signature.is_bare_king(!strong_white) {
+        return false;
+    }
+
+    let (pawn, bishop) = if strong_white {
+        (Piece::WhitePawn, Piece::WhiteBishop)
+    } else {
+        (Piece::BlackPawn, Piece::BlackBishop)
+    };
+    let pawns = *board.get_bit_board(pawn);
+    let file = if (pawns & !file_bit_board(0)).is_empty() {
+        0
+    } else if (pawns & !file_bit_board(7)).is_empty() {
+        7
+    } else {
+        return false;
+    };
+
+    let promotion_square = Square::from_coords(if strong_white { 7 } else { 0 }, file);
+    let light_promotion_square = BitBoard::LIGHT_SQUARES.get(&promotion_square);
+    let light_bishop = board
+        .get_bit_board(bishop)
+        .overlaps(&BitBoard::LIGHT_SQUARES);
+
+    light_bishop != light_promotion_square
+        && distance(king_square(board, !strong_white), promotion_square) <= 1
+}
+
+/// Returns what is known about the endgame on the board, if anything.
+#[must_use]
+pub fn probe(board: &Board) -> Option<Endgame> {
+    let signature = MaterialSignature::new(board);
+
+    for (endgame_signature, specialised) in ENDGAMES {
+        for strong_white in [true, false] {
+            let matches = if strong_white {
+                signature == endgame_signature
+            } else {
+                signature == endgame_signature.flip()
+            };
+            if !matches {
+                continue;
+            }
+
+            match specialised {
+                Specialised::Evaluation(evaluate) => {
+                    let score = evaluate(board, strong_white);
+                    return Some(Endgame::Score(if strong_white { score } else { -score }));
+                }
+                Specialised::Lookup(look_up) => {
+                    if let Some(score) = look_up(board, strong_white) {
+                        return Some(Endgame::Score(if strong_white { score } else { -score }));
+                    }
+                }
+                Specialised::Scale(scale) => {
+                    let mut factors = [SCALE_NORMAL; 2];
+                    factors[usize::from(!strong_white)] = scale(board, strong_white);
+                    return Some(Endgame::Scale(factors));
+                }
+            }
+        }
+    }
+
+    for strong_white in [true, false] {
+        if signature.is_bare_king(!strong_white)
+            && signature.non_pawn_material(strong_white) >= SEE_PIECE_VALUES[3]
+        {
+            let score = king_and_material_king(board, signature, strong_white);
+            return Some(Endgame::Score(if strong_white { score } else { -score }));
+        }
+
+        if is_wrong_bishop_rook_pawn(board, signature, strong_white) {
+            let mut factors = [SCALE_NORMAL; 2];
+            factors[usize::from(!strong_white)] = 0;
+            return Some(Endgame::Scale(factors));
+        }
+    }
+
+    if is_opposite_coloured_bishops(board, signature) {
+        return Some(Endgame::Scale([SCALE_NORMAL * 3 / 8; 2]));
+    }
+
+    None
+}
+
+/// Returns the score of the position from white's perspective, consulting endgame knowledge before falling back to
+/// `tapered_score`.
+pub fn evaluate(board: &Board, tapered_score: impl FnOnce() -> EvalNumber) -> EvalNumber {
+    match probe(board) {
+        Some(Endgame::Score(score)) => score,
+        Some(Endgame::Scale(factors)) => {
+            let score = tapered_score();
+            score * factors[usize::from(score < 0)] / SCALE_NORMAL
+        }
+        None => tapered_score(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        evaluation::{
+            Eval,
+            endgame::{Endgame, KNOWN_WIN, SCALE_NORMAL, probe},
+        },
+    };
+
+    #[test]
+    fn bishop_and_knight_mate_in_the_bishops_corner() {
+        let right_corner = Board::from_fen("7k/8/5K2/8/8/8/8/2B1N3 w - - 0 1").unwrap();
+        let wrong_corner = Board::from_fen("k7/8/2K5/8/8/8/8/2B1N3 w - - 0 1").unwrap();
+        assert!(Eval::evaluate(&right_corner) > Eval::evaluate(&wrong_corner));
+    }
+
+    #[test]
+    fn bare_king_is_driven_to_the_edge() {
+        let edge = Board::from_fen("3k4/8/3K4/8/8/8/8/7R b - - 0 1").unwrap();
+        let centre = Board::from_fen("8/8/8/3k4/8/3K4/8/7R b - - 0 1").unwrap();
+        assert!(Eval::evaluate(&edge) < Eval::evaluate(&centre));
+        assert!(Eval::evaluate(&centre) < -1000);
+    }
+
+    #[test]
+    fn king_and_pawn_against_king_is_looked_up() {
+        let win = Board::from_fen("4k3/8/4K3/4P3/8/8/8/8 b - - 0 1").unwrap();
+        assert!(Eval::evaluate(&win) <= -KNOWN_WIN);
+
+        let draw = Board::from_fen("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1").unwrap();
+        assert_eq!(Eval::evaluate(&draw), 0);
+
+        let black_wins = Board::from_fen("8/8/8/8/4p3/4k3/8/4K3 w - - 0 1").unwrap();
+        assert!(Eval::evaluate(&black_wins) <= -KNOWN_WIN);
+    }
+
+    #[test]
+    fn pawn_on_the_first_or_last_rank_is_not_looked_up() {
+        for fen in [
+            "4k3/8/8/8/8/8/8/P3K3 w - - 0 1",
+            "P3k3/8/8/8/8/8/8/4K3 w - - 0 1",
+            "p3k3/8/8/8/8/8/8/4K3 b - - 0 1",
+            "4k3/8/8/8/8/8/8/p3K3 b - - 0 1",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            assert_eq!(probe(&board), None, "{fen}");
+            assert!(Eval::evaluate(&board) < KNOWN_WIN, "{fen}");
+        }
+    }
+
+    #[test]
+    fn drawish_material_is_scaled() {
+        let rook_against_bishop = Board::from_fen("8/8/3kb3/8/8/3KR3/8/8 w - - 0 1").unwrap();
+        assert_eq!(
+            probe(&rook_against_bishop),
+            Some(Endgame::Scale([SCALE_NORMAL / 8, SCALE_NORMAL]))
+        );
+
+        let opposite_coloured_bishops =
+            Board::from_fen("8/5k2/5p2/3b4/8/2P1B3/1P3K2/8 w - - 0 1").unwrap();
+        assert!(matches!(
+            probe(&opposite_coloured_bishops),
+            Some(Endgame::Scale(_))
+        ));
+
+        let wrong_bishop = Board::from_fen("k7/8/8/8/8/8/P7/K1B5 b - - 0 1").unwrap();
+        assert_eq!(Eval::evaluate(&wrong_bishop), 0);
+        let right_bishop = Board::from_fen("k7/8/8/8/8/8/P7/KB6 b - - 0 1").unwrap();
+        assert!(Eval::evaluate(&right_bishop) < 0);
+    }
+}
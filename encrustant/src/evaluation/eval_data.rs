@@ -137,3 +137,108 @@ pub const END_GAME_PIECE_SQUARE_TABLES: PieceSquareTable = [
 ];
 
 pub const PHASES: [EvalNumber; 5] = [0, 100, 100, 200, 400];
+
+/// Number of handcrafted evaluation terms.
+pub const TERM_COUNT: usize = 92;
+
+/// Weights of the handcrafted evaluation terms, indexed with the offsets below.
+pub type EvalTerms = [i16; TERM_COUNT];
+
+/// Knight mobility, indexed by the number of reachable squares.
+pub const KNIGHT_MOBILITY: usize = 0;
+/// Bishop mobility, indexed by the number of reachable squares.
+pub const BISHOP_MOBILITY: usize = KNIGHT_MOBILITY + 9;
+/// Rook mobility, indexed by the number of reachable squares.
+pub const ROOK_MOBILITY: usize = BISHOP_MOBILITY + 14;
+/// Queen mobility, indexed by the number of reachable squares.
+pub const QUEEN_MOBILITY: usize = ROOK_MOBILITY + 15;
+/// Passed pawns, indexed by the rank relative to the pawn's side.
+pub const PASSED_PAWN: usize = QUEEN_MOBILITY + 28;
+/// Pawns without friendly pawns on the adjacent files.
+pub const ISOLATED_PAWN: usize = PASSED_PAWN + 8;
+/// Pawns with a friendly pawn in front of them.
+pub const DOUBLED_PAWN: usize = ISOLATED_PAWN + 1;
+/// Pawns that can no longer be defended by pawns and whose advance is controlled by an enemy pawn.
+pub const BACKWARD_PAWN: usize = DOUBLED_PAWN + 1;
+/// Having two or more bishops.
+pub const BISHOP_PAIR: usize = BACKWARD_PAWN + 1;
+/// Rooks on a file without pawns.
+pub const ROOK_ON_OPEN_FILE: usize = BISHOP_PAIR + 1;
+/// Rooks on a file with only enemy pawns.
+pub const ROOK_ON_SEMI_OPEN_FILE: usize = ROOK_ON_OPEN_FILE + 1;
+/// Attacks on the enemy king, indexed by the number of pieces attacking the squares around it.
+pub const KING_ATTACKERS: usize = ROOK_ON_SEMI_OPEN_FILE + 1;
+/// Pawn shelter in front of the king, indexed by the number of pawns shielding it.
+pub const PAWN_SHELTER: usize = KING_ATTACKERS + 8;
+
+const _: () = assert!(PAWN_SHELTER + 4 == TERM_COUNT);
+
+/// Handcrafted evaluation terms for the middle game.
+#[rustfmt::skip]
+pub const MIDDLE_GAME_TERMS: EvalTerms = [
+    // Knight mobility
+    -16, -12,  -8,  -4,   0,   4,   8,  12,  16,
+
+    // Bishop mobility
+    -30, -25, -20, -15, -10,  -5,   0,   5,  10,  15,  20,  25,  30,  35,
+
+    // Rook mobility
+    -14, -12, -10,  -8,  -6,  -4,  -2,   0,   2,   4,   6,   8,  10,  12,  14,
+
+    // Queen mobility
+    -13, -12, -11, -10,  -9,  -8,  -7,  -6,  -5,  -4,  -3,  -2,  -1,   0,
+      1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,
+
+    // Passed pawn
+      0,   0,   5,  10,  15,  25,  40,   0,
+
+    // Isolated, doubled and backward pawn
+    -10, -10,  -8,
+
+    // Bishop pair
+     30,
+
+    // Rook on open and semi-open file
+     25,  10,
+
+    // King attackers
+      0,   0,  10,  25,  45,  70, 100, 130,
+
+    // Pawn shelter
+    -20,  -5,   5,  10,
+];
+
+/// Handcrafted evaluation terms for the end game.
+#[rustfmt::skip]
+pub const END_GAME_TERMS: EvalTerms = [
+    // Knight mobility
+    -16, -12,  -8,  -4,   0,   4,   8,  12,  16,
+
+    // Bishop mobility
+    -30, -25, -20, -15, -10,  -5,   0,   5,  10,  15,  20,  25,  30,  35,
+
+    // Rook mobility
+    -28, -24, -20, -16, -12,  -8,  -4,   0,   4,   8,  12,  16,  20,  24,  28,
+
+    // Queen mobility
+    -26, -24, -22, -20, -18, -16, -14, -12, -10,  -8,  -6,  -4,  -2,   0,
+      2,   4,   6,   8,  10,  12,  14,  16,  18,  20,  22,  24,  26,  28,
+
+    // Passed pawn
+      0,   5,  10,  20,  30,  50,  75,   0,
+
+    // Isolated, doubled and backward pawn
+    -10, -20,  -6,
+
+    // Bishop pair
+     50,
+
+    // Rook on open and semi-open file
+     10,   5,
+
+    // King attackers
+      0,   0,   2,   5,   8,  10,  12,  15,
+
+    // Pawn shelter
+      0,   0,   0,   0,
+];
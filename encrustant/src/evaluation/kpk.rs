@@ -0,0 +1,211 @@
+//! King and pawn against king bitbase, generated by retrograde analysis with the `kpk` crate.
+//!
+//! Positions are stored with white having the pawn, which is mirrored onto files a to d.
+
+use crate::board::square::Square;
+
+use super::kpk_bitbase::KPK_BITBASE;
+
+/// Number of positions in the bitbase: side to move, both kings, and a pawn on 24 squares.
+pub const POSITION_COUNT: usize = 2 * 64 * 64 * 24;
+
+/// Returns the index of a position in the bitbase.
+/// The pawn must be on files a to d, and ranks 2 to 7.
+#[must_use]
+#[allow(clippy::cast_sign_loss)] // Files and ranks are never negative
+pub const fn index(
+    white_to_move: bool,
+    white_king: Square,
+    black_king: Square,
+    pawn: Square,
+) -> usize {
+    white_king.usize()
+        | (black_king.usize() << 6)
+        | ((white_to_move as usize) << 12)
+        | ((pawn.file() as usize) << 13)
+        | (((pawn.rank() - 1) as usize) << 15)
+}
+
+/// Returns the side to move, white king, black king and pawn of a position in the bitbase.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub const fn decode(index: usize) -> (bool, Square, Square, Square) {
+    (
+        (index >> 12) & 1 == 1,
+        Square::from_index((index & 63) as i8),
+        Square::from_index(((index >> 6) & 63) as i8),
+        Square::from_coords((index >> 15) as i8 + 1, ((index >> 13) & 3) as i8),
+    )
+}
+
+/// Returns whether white wins with the pawn, or `None` if the pawn is not on ranks 2 to 7.
+#[must_use]
+pub const fn probe(
+    white_to_move: bool,
+    white_king: Square,
+    black_king: Square,
+    pawn: Square,
+) -> Option<bool> {
+    const fn mirror(square: Square) -> Square {
+        Square::from_coords(square.rank(), 7 - square.file())
+    }
+
+    if pawn.rank() < 1 || pawn.rank() > 6 {
+        return None;
+    }
+
+    let (white_king, black_king, pawn) = if pawn.file() > 3 {
+        (mirror(white_king), mirror(black_king), mirror(pawn))
+    } else {
+        (white_king, black_king, pawn)
+    };
+
+    let index = index(white_to_move, white_king, black_king, pawn);
+    Some(KPK_BITBASE[index / 64] & (1 << (index % 64)) != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{Board, piece::Piece, square::Square},
+        evaluation::kpk::{POSITION_COUNT, decode, index, probe},
+        move_generator::{
+            MoveGenerator,
+            move_data::{Flag, Move},
+        },
+    };
+
+    fn board(white_to_move: bool, white_king: Square, black_king: Square, pawn: Square) -> Board {
+        let mut board = Board::from_fen("8/8/8/8/8/8/8/8 w - - 0 1").unwrap();
+        board.white_to_move = white_to_move;
+        board.get_bit_board_mut(Piece::WhiteKing).set(&white_king);
+        board.get_bit_board_mut(Piece::BlackKing).set(&black_king);
+        board.get_bit_board_mut(Piece::WhitePawn).set(&pawn);
+        board
+    }
+
+    fn is_legal(board: &Board, white_king: Square, black_king: Square, pawn: Square) -> bool {
+        if white_king == black_king || white_king == pawn || black_king == pawn {
+            return false;
+        }
+        if (white_king.rank() - black_king.rank()).abs() <= 1
+            && (white_king.file() - black_king.file()).abs() <= 1
+        {
+            return false;
+        }
+
+        // The side not to move can not be in check
+        let mut flipped = board.clone();
+        flipped.white_to_move = !flipped.white_to_move;
+        !MoveGenerator::calculate_is_in_check(&flipped)
+    }
+
+    /// Returns whether white wins with a queen or rook just promoted, with black to move.
+    fn promotion_wins(board: &Board, promotion: Square) -> bool {
+        let move_generator = MoveGenerator::new(board);
+        let mut has_moves = false;
+        let mut captures_promotion = false;
+        move_generator.generate(
+            &mut |move_data: Move| {
+                has_moves = true;
+                captures_promotion |= move_data.to == promotion;
+            },
+            false,
+        );
+        (has_moves || move_generator.is_in_check()) && !captures_promotion
+    }
+
+    fn result(board: &Board) -> bool {
+        let (white_king, black_king) = (
+            board.get_bit_board(Piece::WhiteKing).first_square(),
+            board.get_bit_board(Piece::BlackKing).first_square(),
+        );
+        let pawn = board.get_bit_board(Piece::WhitePawn);
+        if pawn.is_empty() {
+            return false;
+        }
+        probe(
+            board.white_to_move,
+            white_king,
+            black_king,
+            pawn.first_square(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn every_position_agrees_with_its_moves() {
+        let mut checked = 0;
+        for position in 0..POSITION_COUNT {
+            let (white_to_move, white_king, black_king, pawn) = decode(position);
+            assert_eq!(index(white_to_move, white_king, black_king, pawn), position);
+
+            let board = board(white_to_move, white_king, black_king, pawn);
+            if !is_legal(&board, white_king, black_king, pawn) {
+                continue;
+            }
+            checked += 1;
+
+            let move_generator = MoveGenerator::new(&board);
+            let mut children = Vec::new();
+            move_generator.generate(&mut |move_data: Move| children.push(move_data), false);
+
+            let mut child_results = children.iter().map(|move_data| {
+                let mut child = board.clone();
+                child.make_move(move_data);
+                match move_data.flag {
+                    Flag::QueenPromotion | Flag::RookPromotion => {
+                        promotion_wins(&child, move_data.to)
+                    }
+
+                    // A lone minor piece can not mate
+                    Flag::BishopPromotion | Flag::KnightPromotion => false,
+                    _ => result(&child),
+                }
+            });
+
+            // White needs one winning move, black can not have a move that draws
+            let expected = if white_to_move {
+                child_results.any(|wins| wins)
+            } else {
+                !children.is_empty() && child_results.all(|wins| wins)
+                    || children.is_empty() && move_generator.is_in_check()
+            };
+            assert_eq!(result(&board), expected, "{}", board.to_fen());
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn known_positions() {
+        let square = |notation| Square::from_notation(notation).unwrap();
+
+        // The king in front of its pawn with the opposition wins
+        assert_eq!(
+            probe(false, square("e6"), square("e8"), square("e5")),
+            Some(true)
+        );
+
+        // The defending king with the opposition holds the draw
+        assert_eq!(
+            probe(true, square("e5"), square("e7"), square("e4")),
+            Some(false)
+        );
+
+        // Rook pawns are drawn once the defending king reaches the corner
+        assert_eq!(
+            probe(true, square("b6"), square("a8"), square("a5")),
+            Some(false)
+        );
+
+        // Mirrored onto the other side of the board
+        assert_eq!(
+            probe(false, square("d6"), square("d8"), square("d5")),
+            Some(true)
+        );
+
+        // The bitbase has no pawns on the first or last rank
+        assert_eq!(probe(true, square("e1"), square("e8"), square("a1")), None);
+        assert_eq!(probe(true, square("e1"), square("e3"), square("a8")), None);
+    }
+}
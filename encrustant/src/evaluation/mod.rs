@@ -5,8 +5,14 @@ use crate::{
     consume_bit_board,
 };
 
+pub mod endgame;
 pub mod eval_data;
-use eval_data::{EvalNumber, PieceSquareTable};
+pub mod kpk;
+mod kpk_bitbase;
+pub mod nnue;
+pub mod terms;
+use eval_data::{EvalNumber, EvalTerms, PieceSquareTable};
+use terms::PawnStructure;
 
 /// Evaluation functions.
 pub struct Eval;
@@ -70,6 +76,7 @@ impl Eval {
         (middle_game_score * middle_game_phase + end_game_score * end_game_phase) / total_phase
     }
 
+    /// Returns the middle game and end game piece-square-table score from white's perspective.
     #[must_use]
     pub fn raw_evaluate_with_parameters(
         middle_game_piece_square_tables: &PieceSquareTable,
@@ -117,6 +124,8 @@ impl Eval {
     pub fn evaluate_with_parameters(
         middle_game_piece_square_tables: &PieceSquareTable,
         end_game_piece_square_tables: &PieceSquareTable,
+        middle_game_terms: &EvalTerms,
+        end_game_terms: &EvalTerms,
         phases: &[EvalNumber; 5],
         board: &Board,
     ) -> EvalNumber {
@@ -129,18 +138,24 @@ impl Eval {
             + phases[4] * 2
         };
 
-        let (total_middle_game_score, total_end_game_score) = Self::raw_evaluate_with_parameters(
-            middle_game_piece_square_tables,
-            end_game_piece_square_tables,
-            board,
-        );
-        let phase = Self::get_phase(board, phases);
-        Self::calculate_score(
-            phase,
-            total_phase,
-            total_middle_game_score,
-            total_end_game_score,
-        ) * if board.white_to_move { 1 } else { -1 }
+        endgame::evaluate(board, || {
+            let (middle_game_score, end_game_score) = Self::raw_evaluate_with_parameters(
+                middle_game_piece_square_tables,
+                end_game_piece_square_tables,
+                board,
+            );
+            let (middle_game_terms_score, end_game_terms_score) =
+                terms::evaluate_terms(middle_game_terms, end_game_terms, board);
+            let total_middle_game_score = middle_game_score + middle_game_terms_score;
+            let total_end_game_score = end_game_score + end_game_terms_score;
+            let phase = Self::get_phase(board, phases);
+            Self::calculate_score(
+                phase,
+                total_phase,
+                total_middle_game_score,
+                total_end_game_score,
+            )
+        }) * if board.white_to_move { 1 } else { -1 }
     }
 
     /// Returns an estimated score of the position for the side playing.
@@ -149,11 +164,14 @@ impl Eval {
         Self::evaluate_with_parameters(
             &eval_data::MIDDLE_GAME_PIECE_SQUARE_TABLES,
             &eval_data::END_GAME_PIECE_SQUARE_TABLES,
+            &eval_data::MIDDLE_GAME_TERMS,
+            &eval_data::END_GAME_TERMS,
             &eval_data::PHASES,
             board,
         )
     }
 
+    /// Returns the middle game and end game piece-square-table score from white's perspective.
     #[must_use]
     pub fn raw_evaluate(board: &Board) -> (EvalNumber, EvalNumber) {
         Self::raw_evaluate_with_parameters(
@@ -162,11 +180,55 @@ impl Eval {
             board,
         )
     }
+
+    /// Returns the middle game and end game score of the handcrafted terms from white's perspective.
+    #[must_use]
+    pub fn raw_evaluate_terms(board: &Board) -> (EvalNumber, EvalNumber) {
+        terms::evaluate_terms(
+            &eval_data::MIDDLE_GAME_TERMS,
+            &eval_data::END_GAME_TERMS,
+            board,
+        )
+    }
+
+    /// Same as `raw_evaluate_terms`, but with the pawn structure of the board already evaluated.
+    #[must_use]
+    pub fn raw_evaluate_terms_with_pawn_structure(
+        board: &Board,
+        pawn_structure: &mut PawnStructure,
+    ) -> (EvalNumber, EvalNumber) {
+        terms::evaluate_terms_with_pawn_structure(
+            &eval_data::MIDDLE_GAME_TERMS,
+            &eval_data::END_GAME_TERMS,
+            board,
+            pawn_structure,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{board::Board, evaluation::Eval};
+    use crate::{
+        board::Board,
+        evaluation::{
+            Eval,
+            eval_data::{self, EvalNumber, TERM_COUNT},
+        },
+    };
+
+    /// Returns how much the handcrafted terms add to the evaluation of the board.
+    fn terms_score(fen: &str) -> EvalNumber {
+        let board = Board::from_fen(fen).unwrap();
+        let without_terms = Eval::evaluate_with_parameters(
+            &eval_data::MIDDLE_GAME_PIECE_SQUARE_TABLES,
+            &eval_data::END_GAME_PIECE_SQUARE_TABLES,
+            &[0; TERM_COUNT],
+            &[0; TERM_COUNT],
+            &eval_data::PHASES,
+            &board,
+        );
+        Eval::evaluate(&board) - without_terms
+    }
 
     #[test]
     fn advanced_pawn_worth_more() {
@@ -184,4 +246,18 @@ mod tests {
         let knight_on_the_edge = Board::from_fen("7k/8/8/8/7n/8/8/K7 b - - 0 1").unwrap();
         assert!(Eval::evaluate(&centralised_knight) > Eval::evaluate(&knight_on_the_edge));
     }
+
+    #[test]
+    fn passed_pawn_worth_more() {
+        let passed_pawn = terms_score("7k/8/p7/8/3P4/8/8/K7 w - - 0 1");
+        let blocked_pawn = terms_score("7k/8/3p4/8/3P4/8/8/K7 w - - 0 1");
+        assert!(passed_pawn > blocked_pawn);
+    }
+
+    #[test]
+    fn bishop_pair_worth_more() {
+        let bishop_pair = terms_score("7k/7p/8/8/8/8/8/KB1B4 w - - 0 1");
+        let bishop_and_knight = terms_score("7k/7p/8/8/8/8/8/KB1N4 w - - 0 1");
+        assert!(bishop_pair > bishop_and_knight);
+    }
 }
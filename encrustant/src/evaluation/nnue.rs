@@ -0,0 +1,348 @@
+//! Efficiently updatable neural network, with a (768 -> `HIDDEN_SIZE`)x2 -> 1 perspective architecture.
+//!
+//! Each side has an accumulator holding the hidden layer from its own perspective, which is updated
+//! as pieces are added and removed instead of being recalculated for every position.
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    board::{Board, piece::Piece, square::Square},
+    consume_bit_board,
+};
+
+use super::eval_data::EvalNumber;
+
+/// Size of the hidden layer of each perspective.
+pub const HIDDEN_SIZE: usize = 256;
+
+/// One input for each piece on each square.
+const INPUT_SIZE: usize = 768;
+
+/// Quantisation of the feature weights, and the value hidden neurons are clipped at.
+const QA: i32 = 255;
+
+/// Quantisation of the output weights.
+const QB: i32 = 64;
+
+/// Converts the output of the network to centipawns.
+const SCALE: i64 = 400;
+
+/// Size of a network file, which stores every weight as a little-endian `i16`.
+const NETWORK_SIZE: usize = (INPUT_SIZE * HIDDEN_SIZE + HIDDEN_SIZE + 2 * HIDDEN_SIZE + 1) * 2;
+
+/// Hidden layer from the perspective of both sides.
+#[derive(Clone, Copy)]
+pub struct Accumulator {
+    white: [i16; HIDDEN_SIZE],
+    black: [i16; HIDDEN_SIZE],
+}
+
+/// Weights of the network.
+pub struct Network {
+    /// Weights of each of the `INPUT_SIZE` inputs to the hidden layer.
+    feature_weights: Box<[[i16; HIDDEN_SIZE]]>,
+
+    /// Bias of the hidden layer.
+    feature_bias: [i16; HIDDEN_SIZE],
+
+    /// Weights of the hidden layer of the side to move, then of the other side.
+    output_weights: [[i16; HIDDEN_SIZE]; 2],
+
+    /// Bias of the output.
+    output_bias: i16,
+}
+
+impl Network {
+    /// Reads a network from a file.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file could not be read or is not a network.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Reads a network from its bytes. Up to 63 bytes of padding at the end are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the size does not match the network.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < NETWORK_SIZE || bytes.len() - NETWORK_SIZE >= 64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Expected a network of {NETWORK_SIZE} bytes, found {} bytes",
+                    bytes.len()
+                ),
+            ));
+        }
+
+        let mut weights = bytes
+            .chunks_exact(2)
+            .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]));
+        let mut read = |values: &mut [i16]| {
+            for (value, weight) in values.iter_mut().zip(&mut weights) {
+                *value = weight;
+            }
+        };
+
+        let mut network = Self {
+            feature_weights: vec![[0; HIDDEN_SIZE]; INPUT_SIZE].into_boxed_slice(),
+            feature_bias: [0; HIDDEN_SIZE],
+            output_weights: [[0; HIDDEN_SIZE]; 2],
+            output_bias: 0,
+        };
+        for feature in &mut network.feature_weights {
+            read(feature);
+        }
+        read(&mut network.feature_bias);
+        read(&mut network.output_weights[0]);
+        read(&mut network.output_weights[1]);
+        read(core::slice::from_mut(&mut network.output_bias));
+
+        Ok(network)
+    }
+
+    /// Returns the input of a piece on a square, from the perspective of white and of black.
+    const fn feature_indices(piece: Piece, square: Square) -> (usize, usize) {
+        let piece_index = piece as usize;
+        let square_index = square.usize();
+
+        // Black sees the board flipped, with the colours swapped
+        let white_index = piece_index * 64 + square_index;
+        let black_index = ((piece_index + 6) % 12) * 64 + square.flip().usize();
+        (white_index, black_index)
+    }
+
+    /// Calculates the accumulator of `board` from scratch.
+    #[must_use]
+    pub fn accumulator(&self, board: &Board) -> Accumulator {
+        let mut accumulator = Accumulator {
+            white: self.feature_bias,
+            black: self.feature_bias,
+        };
+        for piece in Piece::ALL_PIECES {
+            let mut bit_board = *board.get_bit_board(piece);
+            consume_bit_board!(bit_board, square {
+                self.add_piece(&mut accumulator, piece, square);
+            });
+        }
+        accumulator
+    }
+
+    /// Updates `accumulator` for a piece placed on `square`.
+    pub fn add_piece(&self, accumulator: &mut Accumulator, piece: Piece, square: Square) {
+        let (white_index, black_index) = Self::feature_indices(piece, square);
+        add_weights(&mut accumulator.white, &self.feature_weights[white_index]);
+        add_weights(&mut accumulator.black, &self.feature_weights[black_index]);
+    }
+
+    /// Updates `accumulator` for a piece taken off `square`.
+    pub fn remove_piece(&self, accumulator: &mut Accumulator, piece: Piece, square: Square) {
+        let (white_index, black_index) = Self::feature_indices(piece, square);
+        subtract_weights(&mut accumulator.white, &self.feature_weights[white_index]);
+        subtract_weights(&mut accumulator.black, &self.feature_weights[black_index]);
+    }
+
+    /// Returns an estimated score of the position for the side playing.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn evaluate(&self, accumulator: &Accumulator, white_to_move: bool) -> EvalNumber {
+        let (us, them) = if white_to_move {
+            (&accumulator.white, &accumulator.black)
+        } else {
+            (&accumulator.black, &accumulator.white)
+        };
+
+        let output = i64::from(clipped_dot(us, &self.output_weights[0]))
+            + i64::from(clipped_dot(them, &self.output_weights[1]))
+            + i64::from(self.output_bias) * i64::from(QA);
+
+        // Can not be outside the range of `EvalNumber`
+        (output * SCALE / i64::from(QA * QB)) as EvalNumber
+    }
+}
+
+// Sums wrap for networks with large weights, so undoing an update still gives the same accumulator
+
+fn add_weights(values: &mut [i16; HIDDEN_SIZE], weights: &[i16; HIDDEN_SIZE]) {
+    for (value, weight) in values.iter_mut().zip(weights) {
+        *value = value.wrapping_add(*weight);
+    }
+}
+
+fn subtract_weights(values: &mut [i16; HIDDEN_SIZE], weights: &[i16; HIDDEN_SIZE]) {
+    for (value, weight) in values.iter_mut().zip(weights) {
+        *value = value.wrapping_sub(*weight);
+    }
+}
+
+/// Returns the dot product of `weights` and the hidden neurons clipped between 0 and `QA`.
+fn clipped_dot(neurons: &[i16; HIDDEN_SIZE], weights: &[i16; HIDDEN_SIZE]) -> i32 {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 was detected.
+        return unsafe { avx2::clipped_dot(neurons, weights) };
+    }
+
+    scalar_clipped_dot(neurons, weights)
+}
+
+fn scalar_clipped_dot(neurons: &[i16; HIDDEN_SIZE], weights: &[i16; HIDDEN_SIZE]) -> i32 {
+    neurons
+        .iter()
+        .zip(weights)
+        .map(|(&neuron, &weight)| i32::from(neuron).clamp(0, QA) * i32::from(weight))
+        .sum()
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use core::arch::x86_64::{
+        __m256i, _mm_add_epi32, _mm_cvtsi128_si32, _mm_shuffle_epi32, _mm256_add_epi32,
+        _mm256_castsi256_si128, _mm256_extracti128_si256, _mm256_loadu_si256, _mm256_madd_epi16,
+        _mm256_max_epi16, _mm256_min_epi16, _mm256_set1_epi16, _mm256_setzero_si256,
+    };
+
+    use super::{HIDDEN_SIZE, QA};
+
+    /// `i16` values in a register.
+    const LANES: usize = 16;
+
+    /// # Safety
+    ///
+    /// AVX2 must be available.
+    #[target_feature(enable = "avx2")]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_ptr_alignment)]
+    pub unsafe fn clipped_dot(neurons: &[i16; HIDDEN_SIZE], weights: &[i16; HIDDEN_SIZE]) -> i32 {
+        let zero = _mm256_setzero_si256();
+        let ceiling = _mm256_set1_epi16(QA as i16);
+
+        let mut sum = _mm256_setzero_si256();
+        for index in (0..HIDDEN_SIZE).step_by(LANES) {
+            // SAFETY: `index + LANES` is at most `HIDDEN_SIZE`, and unaligned loads are used.
+            let (neuron, weight) = unsafe {
+                (
+                    _mm256_loadu_si256(neurons.as_ptr().add(index).cast::<__m256i>()),
+                    _mm256_loadu_si256(weights.as_ptr().add(index).cast::<__m256i>()),
+                )
+            };
+            let clipped = _mm256_min_epi16(_mm256_max_epi16(neuron, zero), ceiling);
+            sum = _mm256_add_epi32(sum, _mm256_madd_epi16(clipped, weight));
+        }
+
+        // Add the eight lanes together
+        let half = _mm_add_epi32(
+            _mm256_castsi256_si128(sum),
+            _mm256_extracti128_si256::<1>(sum),
+        );
+        let quarter = _mm_add_epi32(half, _mm_shuffle_epi32::<0b01_00_11_10>(half));
+        let eighth = _mm_add_epi32(quarter, _mm_shuffle_epi32::<0b10_11_00_01>(quarter));
+        _mm_cvtsi128_si32(eighth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, atomic::AtomicBool};
+
+    use crate::{
+        board::{Board, piece::Piece, square::Square},
+        evaluation::nnue::{HIDDEN_SIZE, NETWORK_SIZE, Network, clipped_dot, scalar_clipped_dot},
+        move_generator::MoveGenerator,
+        search::{
+            Search, search_params::DEFAULT_TUNABLES, time_manager::TimeManager,
+            transposition::megabytes_to_capacity,
+        },
+    };
+
+    /// Returns pseudo-random numbers between `-range` and `range`.
+    fn random_weights(count: usize, range: u64) -> impl Iterator<Item = i16> {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        (0..count).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            let weight = (state % (range * 2 + 1)) as i16 - range as i16;
+            weight
+        })
+    }
+
+    /// Makes a network with small pseudo-random weights.
+    fn random_network() -> Network {
+        let bytes: Vec<u8> = random_weights(NETWORK_SIZE / 2, 64)
+            .flat_map(i16::to_le_bytes)
+            .collect();
+        Network::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn network_size_is_checked() {
+        assert!(Network::from_bytes(&[0; NETWORK_SIZE - 2]).is_err());
+        assert!(Network::from_bytes(&[0; NETWORK_SIZE + 64]).is_err());
+        assert!(Network::from_bytes(&[0; NETWORK_SIZE + 32]).is_ok());
+    }
+
+    #[test]
+    fn clipped_dot_matches_scalar() {
+        let mut random = random_weights(HIDDEN_SIZE * 2, 400);
+        let neurons: [i16; HIDDEN_SIZE] = core::array::from_fn(|_| random.next().unwrap());
+        let weights: [i16; HIDDEN_SIZE] = core::array::from_fn(|_| random.next().unwrap());
+        assert_eq!(
+            clipped_dot(&neurons, &weights),
+            scalar_clipped_dot(&neurons, &weights)
+        );
+    }
+
+    #[test]
+    fn accumulator_updates_match_refresh() {
+        let network = Arc::new(random_network());
+        let mut search = Search::new(
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap(),
+            megabytes_to_capacity(8),
+            #[cfg(feature = "spsa")]
+            DEFAULT_TUNABLES,
+        );
+        search.set_network(Some(network.clone()));
+
+        // Every kind of move, including castling, en passant and promotions
+        let mut moves = Vec::new();
+        MoveGenerator::new(search.board()).generate(|move_data| moves.push(move_data), false);
+        for move_data in moves {
+            let old_state = search.make_move::<false>(&move_data);
+            let expected = network.evaluate(
+                &network.accumulator(search.board()),
+                search.board().white_to_move,
+            );
+            assert_eq!(search.static_evaluate(), expected);
+            search.unmake_move(&move_data, &old_state);
+        }
+
+        // A search only uses the network
+        let time_manager = TimeManager::depth_limited(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            None,
+            4,
+        );
+        let (_, score) = search.iterative_deepening(&time_manager, &mut |_| {});
+        assert!(!Search::score_is_checkmate(score));
+        assert!(!search.pv.root_best_move().is_none());
+    }
+
+    #[test]
+    fn large_weights_wrap() {
+        let network = Network::from_bytes(&[0x7F; NETWORK_SIZE]).unwrap();
+        let board = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+        let mut accumulator = network.accumulator(&board);
+        let before = accumulator;
+
+        let e2 = Square::from_notation("e2").unwrap();
+        network.remove_piece(&mut accumulator, Piece::WhitePawn, e2);
+        network.add_piece(&mut accumulator, Piece::WhitePawn, e2);
+        assert!(accumulator.white == before.white && accumulator.black == before.black);
+    }
+}
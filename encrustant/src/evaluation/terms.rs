@@ -0,0 +1,488 @@
+//! Handcrafted evaluation terms that are not tied to a single piece and square.
+
+use crate::{
+    board::{Board, bit_board::BitBoard, piece::Piece, square::Square},
+    consume_bit_board,
+    move_generator::{
+        MoveGenerator,
+        slider_lookup::{
+            get_bishop_moves, get_rook_moves, relevant_bishop_blockers, relevant_rook_blockers,
+        },
+    },
+};
+
+use super::eval_data::{
+    BACKWARD_PAWN, BISHOP_MOBILITY, BISHOP_PAIR, DOUBLED_PAWN, EvalNumber, EvalTerms,
+    ISOLATED_PAWN, KING_ATTACKERS, KNIGHT_MOBILITY, PASSED_PAWN, PAWN_SHELTER, QUEEN_MOBILITY,
+    ROOK_MOBILITY, ROOK_ON_OPEN_FILE, ROOK_ON_SEMI_OPEN_FILE,
+};
+
+const A_FILE: u64 = 0x0101_0101_0101_0101;
+
+/// Returns the squares of a file.
+pub(super) const fn file_bit_board(file: i8) -> BitBoard {
+    BitBoard::new(A_FILE << file)
+}
+
+fn adjacent_files(file: i8) -> BitBoard {
+    let mut adjacent = BitBoard::EMPTY;
+    if file > 0 {
+        adjacent |= file_bit_board(file - 1);
+    }
+    if file < 7 {
+        adjacent |= file_bit_board(file + 1);
+    }
+    adjacent
+}
+
+/// Ranks strictly in front of `rank` from the point of view of the given side.
+const fn ranks_in_front(rank: i8, white: bool) -> BitBoard {
+    let rank = rank.unsigned_abs() as u32;
+    if white {
+        BitBoard::new(match (!0_u64).checked_shl(8 * (rank + 1)) {
+            Some(bits) => bits,
+            None => 0,
+        })
+    } else {
+        BitBoard::new((1_u64 << (8 * rank)) - 1)
+    }
+}
+
+/// The two ranks in front of `rank` from the point of view of the given side.
+fn shield_ranks(rank: i8, white: bool) -> BitBoard {
+    let mut ranks = BitBoard::EMPTY;
+    for distance in 1..=2 {
+        let shield_rank = if white {
+            rank + distance
+        } else {
+            rank - distance
+        };
+        if (0..8).contains(&shield_rank) {
+            ranks |= BitBoard::RANK_1 << (shield_rank.unsigned_abs() * 8);
+        }
+    }
+    ranks
+}
+
+fn pawn_attacks(pawns: BitBoard, white: bool) -> BitBoard {
+    if white {
+        ((pawns & BitBoard::NOT_A_FILE) << 7) | ((pawns & BitBoard::NOT_H_FILE) << 9)
+    } else {
+        ((pawns & BitBoard::NOT_A_FILE) >> 9) | ((pawns & BitBoard::NOT_H_FILE) >> 7)
+    }
+}
+
+struct Side {
+    pawns: BitBoard,
+    knights: BitBoard,
+    bishops: BitBoard,
+    rooks: BitBoard,
+    queens: BitBoard,
+    king: BitBoard,
+    pieces: BitBoard,
+    pawn_attacks: BitBoard,
+}
+
+impl Side {
+    fn new(board: &Board, white: bool) -> Self {
+        let pieces = if white {
+            Piece::WHITE_PIECES
+        } else {
+            Piece::BLACK_PIECES
+        };
+        let [pawns, knights, bishops, rooks, queens, king] =
+            pieces.map(|piece| *board.get_bit_board(piece));
+
+        Self {
+            pawns,
+            knights,
+            bishops,
+            rooks,
+            queens,
+            king,
+            pieces: pawns | knights | bishops | rooks | queens | king,
+            pawn_attacks: pawn_attacks(pawns, white),
+        }
+    }
+}
+
+struct Score<'a> {
+    middle_game_terms: &'a EvalTerms,
+    end_game_terms: &'a EvalTerms,
+    middle_game: EvalNumber,
+    end_game: EvalNumber,
+}
+
+impl<'a> Score<'a> {
+    const fn new(middle_game_terms: &'a EvalTerms, end_game_terms: &'a EvalTerms) -> Self {
+        Self {
+            middle_game_terms,
+            end_game_terms,
+            middle_game: 0,
+            end_game: 0,
+        }
+    }
+
+    fn add(&mut self, term: usize) {
+        self.middle_game += EvalNumber::from(self.middle_game_terms[term]);
+        self.end_game += EvalNumber::from(self.end_game_terms[term]);
+    }
+}
+
+/// Scores the pawns of one side and returns which of them are passed.
+fn evaluate_pawns(score: &mut Score, friendly: &Side, enemy: &Side, white: bool) -> BitBoard {
+    let mut passed_pawns = BitBoard::EMPTY;
+    let mut pawns = friendly.pawns;
+    consume_bit_board!(pawns, square {
+        let (rank, file) = (square.rank(), square.file());
+        let in_front = ranks_in_front(rank, white);
+        let adjacent = adjacent_files(file);
+
+        if (enemy.pawns & in_front & (adjacent | file_bit_board(file))).is_empty() {
+            passed_pawns.set(&square);
+            let relative_rank = if white { rank } else { 7 - rank };
+            score.add(PASSED_PAWN + usize::from(relative_rank.unsigned_abs()));
+        }
+
+        if (friendly.pawns & in_front & file_bit_board(file)).is_not_empty() {
+            score.add(DOUBLED_PAWN);
+        }
+
+        if (friendly.pawns & adjacent).is_empty() {
+            score.add(ISOLATED_PAWN);
+        } else {
+            let stop_square = if white { square.up(1) } else { square.down(1) };
+            let can_be_supported = (friendly.pawns & adjacent & !in_front).is_not_empty();
+            if !can_be_supported && enemy.pawn_attacks.get(&stop_square) {
+                score.add(BACKWARD_PAWN);
+            }
+        }
+    });
+    passed_pawns
+}
+
+fn evaluate_shelter(score: &mut Score, pawns: BitBoard, king_square: Square, white: bool) {
+    let file = king_square.file();
+    let shield = (adjacent_files(file) | file_bit_board(file))
+        & shield_ranks(king_square.rank(), white)
+        & pawns;
+    score.add(PAWN_SHELTER + (shield.count() as usize).min(3));
+}
+
+/// Evaluation terms that only depend on the pawns, so they can be cached by the pawn zobrist key.
+#[derive(Clone, Copy)]
+pub struct PawnStructure {
+    /// Passed pawns of white and black.
+    pub passed_pawns: [BitBoard; 2],
+
+    /// Middle game and end game score of the pawns from white's perspective.
+    pub score: (EvalNumber, EvalNumber),
+
+    /// Pawn shelter score of white and black, with the king square it was calculated for.
+    shelter: [Option<(Square, EvalNumber, EvalNumber)>; 2],
+}
+
+impl PawnStructure {
+    /// Evaluates the pawns of the board.
+    #[must_use]
+    pub fn new(middle_game_terms: &EvalTerms, end_game_terms: &EvalTerms, board: &Board) -> Self {
+        let white = Side::new(board, true);
+        let black = Side::new(board, false);
+
+        let mut white_score = Score::new(middle_game_terms, end_game_terms);
+        let white_passed_pawns = evaluate_pawns(&mut white_score, &white, &black, true);
+        let mut black_score = Score::new(middle_game_terms, end_game_terms);
+        let black_passed_pawns = evaluate_pawns(&mut black_score, &black, &white, false);
+
+        Self {
+            passed_pawns: [white_passed_pawns, black_passed_pawns],
+            score: (
+                white_score.middle_game - black_score.middle_game,
+                white_score.end_game - black_score.end_game,
+            ),
+            shelter: [None; 2],
+        }
+    }
+
+    /// Returns the middle game and end game pawn shelter score of a side's king.
+    /// The score is cached until the king moves.
+    pub fn king_shelter(
+        &mut self,
+        middle_game_terms: &EvalTerms,
+        end_game_terms: &EvalTerms,
+        board: &Board,
+        white: bool,
+    ) -> (EvalNumber, EvalNumber) {
+        let (pawn, king) = if white {
+            (Piece::WhitePawn, Piece::WhiteKing)
+        } else {
+            (Piece::BlackPawn, Piece::BlackKing)
+        };
+        let king = *board.get_bit_board(king);
+        if king.is_empty() {
+            return (0, 0);
+        }
+        let king_square = king.first_square();
+
+        let shelter = &mut self.shelter[usize::from(!white)];
+        if let Some((square, middle_game, end_game)) = *shelter
+            && square == king_square
+        {
+            return (middle_game, end_game);
+        }
+
+        let mut score = Score::new(middle_game_terms, end_game_terms);
+        evaluate_shelter(&mut score, *board.get_bit_board(pawn), king_square, white);
+        *shelter = Some((king_square, score.middle_game, score.end_game));
+        (score.middle_game, score.end_game)
+    }
+}
+
+fn evaluate_pieces(score: &mut Score, friendly: &Side, enemy: &Side, occupied: BitBoard) {
+    let mobility_area = !friendly.pieces & !enemy.pawn_attacks;
+    let enemy_king_zone = if enemy.king.is_empty() {
+        BitBoard::EMPTY
+    } else {
+        MoveGenerator::king_attack_bit_board(enemy.king.first_square()) | enemy.king
+    };
+    let mut king_attackers = 0;
+
+    let mut attacks = |piece_attacks: BitBoard, mobility: usize| {
+        score.add(mobility + (piece_attacks & mobility_area).count() as usize);
+        if piece_attacks.overlaps(&enemy_king_zone) {
+            king_attackers += 1;
+        }
+    };
+
+    let bishop_attacks =
+        |square: Square| get_bishop_moves(square, occupied & relevant_bishop_blockers(square));
+    let rook_attacks =
+        |square: Square| get_rook_moves(square, occupied & relevant_rook_blockers(square));
+
+    let mut knights = friendly.knights;
+    consume_bit_board!(knights, square {
+        attacks(MoveGenerator::knight_attack_bit_board(square), KNIGHT_MOBILITY);
+    });
+
+    let mut bishops = friendly.bishops;
+    consume_bit_board!(bishops, square {
+        attacks(bishop_attacks(square), BISHOP_MOBILITY);
+    });
+
+    let mut rooks = friendly.rooks;
+    consume_bit_board!(rooks, square {
+        attacks(rook_attacks(square), ROOK_MOBILITY);
+    });
+
+    let mut queens = friendly.queens;
+    consume_bit_board!(queens, square {
+        attacks(bishop_attacks(square) | rook_attacks(square), QUEEN_MOBILITY);
+    });
+
+    score.add(KING_ATTACKERS + king_attackers.min(7));
+
+    if friendly.bishops.more_than_one_bit_set() {
+        score.add(BISHOP_PAIR);
+    }
+
+    let mut rooks = friendly.rooks;
+    consume_bit_board!(rooks, square {
+        let file = file_bit_board(square.file());
+        if (file & friendly.pawns).is_empty() {
+            if (file & enemy.pawns).is_empty() {
+                score.add(ROOK_ON_OPEN_FILE);
+            } else {
+                score.add(ROOK_ON_SEMI_OPEN_FILE);
+            }
+        }
+    });
+}
+
+/// Returns the middle game and end game score of the handcrafted terms, from white's perspective.
+#[must_use]
+pub fn evaluate_terms(
+    middle_game_terms: &EvalTerms,
+    end_game_terms: &EvalTerms,
+    board: &Board,
+) -> (EvalNumber, EvalNumber) {
+    let mut pawn_structure = PawnStructure::new(middle_game_terms, end_game_terms, board);
+    evaluate_terms_with_pawn_structure(
+        middle_game_terms,
+        end_game_terms,
+        board,
+        &mut pawn_structure,
+    )
+}
+
+/// Returns the middle game and end game score of the handcrafted terms, from white's perspective,
+/// using an already evaluated pawn structure of the board.
+#[must_use]
+pub fn evaluate_terms_with_pawn_structure(
+    middle_game_terms: &EvalTerms,
+    end_game_terms: &EvalTerms,
+    board: &Board,
+    pawn_structure: &mut PawnStructure,
+) -> (EvalNumber, EvalNumber) {
+    let white = Side::new(board, true);
+    let black = Side::new(board, false);
+    let occupied = white.pieces | black.pieces;
+
+    let mut white_score = Score::new(middle_game_terms, end_game_terms);
+    evaluate_pieces(&mut white_score, &white, &black, occupied);
+    let mut black_score = Score::new(middle_game_terms, end_game_terms);
+    evaluate_pieces(&mut black_score, &black, &white, occupied);
+
+    let (pawn_middle_game, pawn_end_game) = pawn_structure.score;
+    let (white_shelter_middle_game, white_shelter_end_game) =
+        pawn_structure.king_shelter(middle_game_terms, end_game_terms, board, true);
+    let (black_shelter_middle_game, black_shelter_end_game) =
+        pawn_structure.king_shelter(middle_game_terms, end_game_terms, board, false);
+
+    (
+        white_score.middle_game - black_score.middle_game
+            + pawn_middle_game
+            + white_shelter_middle_game
+            - black_shelter_middle_game,
+        white_score.end_game - black_score.end_game + pawn_end_game + white_shelter_end_game
+            - black_shelter_end_game,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        evaluation::{
+            eval_data::{
+                BACKWARD_PAWN, BISHOP_MOBILITY, BISHOP_PAIR, DOUBLED_PAWN, END_GAME_TERMS,
+                EvalTerms, ISOLATED_PAWN, KING_ATTACKERS, KNIGHT_MOBILITY, MIDDLE_GAME_TERMS,
+                PASSED_PAWN, PAWN_SHELTER, QUEEN_MOBILITY, ROOK_MOBILITY, ROOK_ON_OPEN_FILE,
+                ROOK_ON_SEMI_OPEN_FILE, TERM_COUNT,
+            },
+            terms::evaluate_terms,
+        },
+    };
+
+    /// Returns the board with the colours swapped and the ranks flipped.
+    fn mirror(fen: &str) -> Board {
+        let swap_case = |text: &str| -> String {
+            text.chars()
+                .map(|character| {
+                    if character.is_ascii_uppercase() {
+                        character.to_ascii_lowercase()
+                    } else {
+                        character.to_ascii_uppercase()
+                    }
+                })
+                .collect()
+        };
+
+        let fields: Vec<&str> = fen.split(' ').collect();
+        let placement: Vec<String> = fields[0].split('/').rev().map(swap_case).collect();
+        let side_to_move = if fields[1] == "w" { "b" } else { "w" };
+        let mut castling: Vec<char> = swap_case(fields[2]).chars().collect();
+        castling.sort_by_key(|&right| "KQkq-".find(right));
+        let en_passant = fields[3]
+            .replace('3', "x")
+            .replace('6', "3")
+            .replace('x', "6");
+
+        Board::from_fen(&format!(
+            "{} {side_to_move} {} {en_passant} {} {}",
+            placement.join("/"),
+            castling.into_iter().collect::<String>(),
+            fields[4],
+            fields[5],
+        ))
+        .unwrap()
+    }
+
+    /// Weights a group of terms by their position in it, counting from one, and the rest by zero.
+    fn group_weights(first: usize, count: usize) -> EvalTerms {
+        let mut terms = [0; TERM_COUNT];
+        for (weight, term) in (1..).zip(first..first + count) {
+            terms[term] = weight;
+        }
+        terms
+    }
+
+    #[test]
+    fn terms_count_for_the_side_with_them() {
+        for (first, count, fen) in [
+            (KNIGHT_MOBILITY, 9, "4k3/8/8/8/4N3/8/8/4K3 w - - 0 1"),
+            (BISHOP_MOBILITY, 14, "4k3/8/8/8/4B3/8/8/4K3 w - - 0 1"),
+            (ROOK_MOBILITY, 15, "4k3/8/8/8/4R3/8/8/4K3 w - - 0 1"),
+            (QUEEN_MOBILITY, 28, "4k3/8/8/8/4Q3/8/8/4K3 w - - 0 1"),
+            (PASSED_PAWN, 8, "4k3/8/8/8/3P4/8/8/4K3 w - - 0 1"),
+            (ISOLATED_PAWN, 1, "4k3/8/8/8/3P4/8/8/4K3 w - - 0 1"),
+            (DOUBLED_PAWN, 1, "4k3/8/8/3P4/3P4/8/8/4K3 w - - 0 1"),
+            (BACKWARD_PAWN, 1, "4k3/8/8/4p3/2P5/3P4/8/4K3 w - - 0 1"),
+            (BISHOP_PAIR, 1, "4k3/8/8/8/8/8/8/2B1KB2 w - - 0 1"),
+            (ROOK_ON_OPEN_FILE, 1, "4k3/8/8/8/8/8/8/R3K3 w - - 0 1"),
+            (ROOK_ON_SEMI_OPEN_FILE, 1, "4k3/p7/8/8/8/8/8/R3K3 w - - 0 1"),
+            (KING_ATTACKERS, 8, "4k3/8/8/8/8/8/8/3QK3 w - - 0 1"),
+            (PAWN_SHELTER, 4, "4k3/8/8/8/8/8/5PPP/6K1 w - - 0 1"),
+        ] {
+            let terms = group_weights(first, count);
+            let (middle_game, end_game) =
+                evaluate_terms(&terms, &terms, &Board::from_fen(fen).unwrap());
+            assert!(middle_game > 0 && end_game > 0, "{fen}");
+
+            // The same for black counts against white
+            assert_eq!(
+                evaluate_terms(&terms, &terms, &mirror(fen)),
+                (-middle_game, -end_game),
+                "{fen}"
+            );
+        }
+    }
+
+    #[test]
+    fn weights_have_the_expected_sign() {
+        for terms in [MIDDLE_GAME_TERMS, END_GAME_TERMS] {
+            assert!(
+                terms[PASSED_PAWN..PASSED_PAWN + 8]
+                    .iter()
+                    .all(|&weight| weight >= 0)
+            );
+            for penalty in [ISOLATED_PAWN, DOUBLED_PAWN, BACKWARD_PAWN] {
+                assert!(terms[penalty] <= 0);
+            }
+            for bonus in [BISHOP_PAIR, ROOK_ON_OPEN_FILE, ROOK_ON_SEMI_OPEN_FILE] {
+                assert!(terms[bonus] >= 0);
+            }
+
+            // More is never worse
+            for (first, count) in [
+                (KNIGHT_MOBILITY, 9),
+                (BISHOP_MOBILITY, 14),
+                (ROOK_MOBILITY, 15),
+                (QUEEN_MOBILITY, 28),
+                (KING_ATTACKERS, 8),
+                (PAWN_SHELTER, 4),
+            ] {
+                assert!(terms[first..first + count].is_sorted());
+            }
+        }
+    }
+
+    #[test]
+    fn mirrored_positions_have_opposite_scores() {
+        for fen in [
+            Board::START_POSITION_FEN,
+            "r1bqk2r/pp2bppp/2n1pn2/2pp4/3P4/2PBPN2/PP1N1PPP/R1BQK2R w KQkq - 0 7",
+            "2r3k1/1p3ppp/p1n1p3/3pP3/1q1P4/1P1Q1N2/P4PPP/2R3K1 b - - 0 22",
+            "8/5k2/3p4/1p1Pp1p1/1P2P1P1/5K2/8/8 w - - 0 40",
+            "r3k2r/ppp2ppp/2n5/3qp3/3P4/2P2N2/P1Q2PPP/R3K2R b KQkq d3 0 12",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let (middle_game, end_game) =
+                evaluate_terms(&MIDDLE_GAME_TERMS, &END_GAME_TERMS, &board);
+            assert_eq!(
+                evaluate_terms(&MIDDLE_GAME_TERMS, &END_GAME_TERMS, &mirror(fen)),
+                (-middle_game, -end_game),
+                "{fen}"
+            );
+        }
+    }
+}
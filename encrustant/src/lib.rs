@@ -11,10 +11,12 @@
 #![warn(missing_docs)]
 
 pub mod board;
+pub mod book;
 pub mod evaluation;
 pub mod move_generator;
 pub mod perft;
 pub mod search;
+pub mod tablebase;
 pub mod timer;
 pub mod uci;
 
@@ -22,7 +24,14 @@ pub mod uci;
 mod tests {
     use std::time::Instant;
 
-    use crate::perft::perft_root;
+    use crate::{
+        board::square::Square,
+        move_generator::{
+            MoveGenerator,
+            move_data::{Flag, Move},
+        },
+        perft::perft_root,
+    };
 
     use super::board::Board;
 
@@ -112,4 +121,63 @@ mod tests {
             println!();
         }
     }
+
+    #[test]
+    fn captures_and_quiets_partition_moves() {
+        for (_, _, fen) in TEST_FENS {
+            let board = Board::from_fen(fen).unwrap();
+            let move_generator = MoveGenerator::new(&board);
+
+            let mut moves = Vec::new();
+            move_generator.generate(|move_data| moves.push(move_data), false);
+
+            let mut split_moves = Vec::new();
+            move_generator.generate(|move_data| split_moves.push(move_data), true);
+            move_generator.generate_quiets(|move_data| split_moves.push(move_data));
+
+            assert_eq!(moves.len(), split_moves.len(), "{fen}");
+            for move_data in moves {
+                assert!(split_moves.contains(&move_data), "{fen}");
+            }
+        }
+    }
+
+    fn assert_legality_matches_generation(board: &Board) {
+        let move_generator = MoveGenerator::new(board);
+        let mut moves = Vec::new();
+        move_generator.generate(|move_data| moves.push(move_data), false);
+
+        for from in 0..64 {
+            for to in 0..64 {
+                for flag in Flag::ALL {
+                    let move_data = Move {
+                        from: Square::from_index(from),
+                        to: Square::from_index(to),
+                        flag,
+                    };
+                    let is_legal = moves.contains(&move_data);
+                    assert_eq!(move_generator.is_legal(move_data), is_legal, "{move_data}");
+                    if is_legal {
+                        assert!(move_generator.is_pseudo_legal(move_data), "{move_data}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn legality_check_matches_generation() {
+        for (_, _, fen) in TEST_FENS {
+            let mut board = Board::from_fen(fen).unwrap();
+            assert_legality_matches_generation(&board);
+
+            let mut moves = Vec::new();
+            MoveGenerator::new(&board).generate(|move_data| moves.push(move_data), false);
+            for move_data in moves {
+                let old_state = board.make_move(&move_data);
+                assert_legality_matches_generation(&board);
+                board.unmake_move(&move_data, &old_state);
+            }
+        }
+    }
 }
@@ -13,7 +13,7 @@ use encrustant::{
     board::Board,
     search::{Search, time_manager::TimeManager, transposition::megabytes_to_capacity},
     timer::Time,
-    uci::{GoParameters, SpinU16, UCIProcessor},
+    uci::{GoParameters, SpinU16, UCIProcessor, UciError},
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -526,16 +526,21 @@ fn bench() {
 fn process_input(input: &str) -> bool {
     let mut quit = false;
     let mut args = input.split_whitespace();
-    UCI_PROCESSOR.with(|uci_processor| match args.next().expect("Empty input") {
+    let Some(command) = args.next() else {
+        // Empty lines are ignored
+        return false;
+    };
+    let result = UCI_PROCESSOR.with(|uci_processor| match command {
         "isready" => uci_processor.borrow().isready(),
         "go" => {
             let mut parameters = GoParameters::empty();
-            parameters.parse(&mut args);
+            parameters.parse(&mut args)?;
             uci_processor.borrow_mut().go(parameters)
         }
         "position" => uci_processor.borrow_mut().position(&mut args),
         "ucinewgame" => uci_processor.borrow_mut().ucinewgame(),
         "setoption" => uci_processor.borrow_mut().setoption(input),
+        "debug" => uci_processor.borrow_mut().debug(&mut args),
 
         #[cfg(not(target_arch = "wasm32"))]
         "ponderhit" => uci_processor.borrow().ponderhit(),
@@ -544,14 +549,21 @@ fn process_input(input: &str) -> bool {
 
         #[cfg(not(target_arch = "wasm32"))]
         "stop" => uci_processor.borrow().stop(),
-        "quit" => quit = true,
+        "quit" => {
+            quit = true;
+            Ok(())
+        }
 
         "bench" => {
             bench();
+            Ok(())
         }
 
-        _ => panic!("Unrecognised command"),
+        _ => Err(UciError::UnknownCommand(command.to_owned())),
     });
+    if let Err(error) = result {
+        out(&format!("info string {error}"));
+    }
     quit
 }
 
@@ -569,7 +581,10 @@ fn main() {
 
     loop {
         let mut input = String::new();
-        stdin().read_line(&mut input).unwrap();
+        if stdin().read_line(&mut input).unwrap() == 0 {
+            // End of input, so nothing more can be sent
+            break;
+        }
 
         let quit = process_input(&input);
         if quit {
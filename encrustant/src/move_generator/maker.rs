@@ -71,6 +71,7 @@ impl Board {
                 let rook_from = &move_data.to.offset(rook_from_offset);
                 let rook_to = &move_data.to.offset(rook_to_offset);
                 rook_bit_board.toggle_two(rook_from, rook_to);
+                self.game_state.captured = None;
             }
             Flag::EnPassant => {
                 let capture_position =
@@ -96,6 +97,15 @@ impl Board {
             }
         }
 
+        if piece == Piece::WhitePawn
+            || piece == Piece::BlackPawn
+            || self.game_state.captured.is_some()
+        {
+            self.game_state.half_move_clock = 0;
+        } else {
+            self.game_state.half_move_clock += 1;
+        }
+
         self.white_to_move = !white_to_move;
 
         old_state
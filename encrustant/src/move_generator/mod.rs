@@ -21,12 +21,25 @@ pub mod slider_keys;
 /// Handles slider look up.
 pub mod slider_lookup;
 
+/// Static exchange evaluation.
+pub mod static_exchange;
+
 use self::move_data::{Flag, Move};
 use self::precomputed::{KING_MOVES_AT_SQUARE, KNIGHT_MOVES_AT_SQUARE};
 use self::slider_lookup::{
     get_bishop_moves, get_rook_moves, relevant_bishop_blockers, relevant_rook_blockers,
 };
 
+/// Which moves to generate.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MoveKind {
+    All,
+    /// Captures, including en passant and promotions by capturing.
+    Captures,
+    /// Every move that is not in `Captures`.
+    Quiets,
+}
+
 /// Legal move generator.
 #[allow(clippy::struct_excessive_bools)]
 pub struct MoveGenerator {
@@ -65,24 +78,31 @@ pub struct MoveGenerator {
 }
 
 impl MoveGenerator {
-    fn gen_pawns<F: FnMut(Move)>(&self, add_move: &mut F, captures_only: bool) {
-        pawn_move_generator::generate(self, add_move, captures_only);
+    fn gen_pawns<F: FnMut(Move)>(&self, add_move: &mut F, kind: MoveKind) {
+        pawn_move_generator::generate(self, add_move, kind);
+    }
+
+    fn target_squares(&self, kind: MoveKind) -> BitBoard {
+        match kind {
+            MoveKind::All => !self.friendly_piece_bit_board,
+            MoveKind::Captures => self.enemy_piece_bit_board,
+            MoveKind::Quiets => self.empty_squares,
+        }
     }
 }
 
 impl MoveGenerator {
-    const fn knight_attack_bit_board(square: Square) -> BitBoard {
+    /// Returns the squares a knight on `square` attacks.
+    #[must_use]
+    pub const fn knight_attack_bit_board(square: Square) -> BitBoard {
         KNIGHT_MOVES_AT_SQUARE[square.usize()]
     }
 
-    fn gen_knights<F: FnMut(Move)>(&self, add_move: &mut F, captures_only: bool) {
+    fn gen_knights<F: FnMut(Move)>(&self, add_move: &mut F, kind: MoveKind) {
         let mut non_pinned_knights =
             self.friendly_knights & !(self.diagonal_pin_rays | self.orthogonal_pin_rays);
 
-        let mut mask = (self.empty_squares | self.enemy_piece_bit_board) & self.check_mask;
-        if captures_only {
-            mask &= self.enemy_piece_bit_board;
-        }
+        let mask = self.target_squares(kind) & self.check_mask;
 
         consume_bit_board!(non_pinned_knights, from {
             let mut knight_moves = Self::knight_attack_bit_board(from) & mask;
@@ -99,14 +119,10 @@ impl MoveGenerator {
 }
 
 impl MoveGenerator {
-    fn gen_bishop<F: FnMut(Move)>(&self, from: Square, add_move: &mut F, captures_only: bool) {
+    fn gen_bishop<F: FnMut(Move)>(&self, from: Square, add_move: &mut F, kind: MoveKind) {
         let blockers = self.occupied_squares & relevant_bishop_blockers(from);
         let possible_moves = get_bishop_moves(from, blockers);
-        let mut legal_moves =
-            possible_moves & ((self.enemy_piece_bit_board | self.empty_squares) & self.check_mask);
-        if captures_only {
-            legal_moves &= self.enemy_piece_bit_board;
-        }
+        let mut legal_moves = possible_moves & self.target_squares(kind) & self.check_mask;
         if self.diagonal_pin_rays.get(&from) {
             legal_moves &= self.diagonal_pin_rays;
         }
@@ -119,14 +135,10 @@ impl MoveGenerator {
             });
         });
     }
-    fn gen_rook<F: FnMut(Move)>(&self, from: Square, add_move: &mut F, captures_only: bool) {
+    fn gen_rook<F: FnMut(Move)>(&self, from: Square, add_move: &mut F, kind: MoveKind) {
         let blockers = self.occupied_squares & relevant_rook_blockers(from);
         let possible_moves = get_rook_moves(from, blockers);
-        let mut legal_moves =
-            possible_moves & ((self.enemy_piece_bit_board | self.empty_squares) & self.check_mask);
-        if captures_only {
-            legal_moves &= self.enemy_piece_bit_board;
-        }
+        let mut legal_moves = possible_moves & self.target_squares(kind) & self.check_mask;
         if self.orthogonal_pin_rays.get(&from) {
             legal_moves &= self.orthogonal_pin_rays;
         }
@@ -229,18 +241,17 @@ impl MoveGenerator {
         bishop_attacks
     }
 
-    const fn king_attack_bit_board(square: Square) -> BitBoard {
+    /// Returns the squares a king on `square` attacks.
+    #[must_use]
+    pub const fn king_attack_bit_board(square: Square) -> BitBoard {
         KING_MOVES_AT_SQUARE[square.usize()]
     }
 
     #[allow(clippy::unreadable_literal)]
-    fn gen_king<F: FnMut(Move)>(&self, add_move: &mut F, captures_only: bool) {
+    fn gen_king<F: FnMut(Move)>(&self, add_move: &mut F, kind: MoveKind) {
         let mut king_moves = Self::king_attack_bit_board(self.friendly_king_square)
-            & !self.friendly_piece_bit_board
+            & self.target_squares(kind)
             & !self.king_danger_bit_board;
-        if captures_only {
-            king_moves &= self.enemy_piece_bit_board;
-        }
 
         consume_bit_board!(king_moves, to {
             add_move(Move {
@@ -250,50 +261,145 @@ impl MoveGenerator {
             });
         });
 
-        if self.is_in_check || captures_only {
+        if self.is_in_check || kind == MoveKind::Captures {
             return;
         }
 
-        let cannot_castle_into = self.occupied_squares | self.king_danger_bit_board;
-        if self.king_side {
-            let to = self.friendly_king_square.right(2);
-            let castle_mask = if self.white_to_move {
-                BitBoard::new(0b01100000)
-            } else {
-                BitBoard::new(0b01100000 << 56)
+        if self.can_castle(true) {
+            add_move(Move {
+                from: self.friendly_king_square,
+                to: self.friendly_king_square.right(2),
+                flag: Flag::Castle,
+            });
+        }
+        if self.can_castle(false) {
+            add_move(Move {
+                from: self.friendly_king_square,
+                to: self.friendly_king_square.left(2),
+                flag: Flag::Castle,
+            });
+        }
+    }
+
+    /// Returns the squares that must be empty, and the squares the king must not be attacked on, to castle.
+    #[allow(clippy::unreadable_literal)]
+    const fn castle_masks(&self, king_side: bool) -> (BitBoard, BitBoard) {
+        let (block_mask, castle_mask) = if king_side {
+            (0b01100000, 0b01100000)
+        } else {
+            (0b00001110, 0b00001100)
+        };
+        if self.white_to_move {
+            (BitBoard::new(block_mask), BitBoard::new(castle_mask))
+        } else {
+            (
+                BitBoard::new(block_mask << 56),
+                BitBoard::new(castle_mask << 56),
+            )
+        }
+    }
+
+    /// Whether the castling right is kept and nothing is between the king and the rook.
+    fn can_castle_ignoring_checks(&self, king_side: bool) -> bool {
+        let has_right = if king_side {
+            self.king_side
+        } else {
+            self.queen_side
+        };
+        has_right
+            && !self
+                .castle_masks(king_side)
+                .0
+                .overlaps(&self.occupied_squares)
+    }
+
+    fn can_castle(&self, king_side: bool) -> bool {
+        !self.is_in_check
+            && self.can_castle_ignoring_checks(king_side)
+            && !self
+                .castle_masks(king_side)
+                .1
+                .overlaps(&self.king_danger_bit_board)
+    }
+}
+
+impl MoveGenerator {
+    /// Whether the move could be made by a friendly piece by how it moves, ignoring whether it leaves the king in check.
+    ///
+    /// Moves remembered from other positions, like hash moves and killer moves, should be checked before being made.
+    #[must_use]
+    pub fn is_pseudo_legal(&self, move_data: Move) -> bool {
+        let Move { from, to, flag } = move_data;
+        if !self.friendly_piece_bit_board.get(&from) || self.friendly_piece_bit_board.get(&to) {
+            return false;
+        }
+
+        if self.friendly_pawns.get(&from) {
+            return pawn_move_generator::is_pseudo_legal(self, move_data);
+        }
+        if from == self.friendly_king_square {
+            return match flag {
+                Flag::None => Self::king_attack_bit_board(from).get(&to),
+                Flag::Castle => {
+                    (self.king_side && to == from.right(2) && self.can_castle_ignoring_checks(true))
+                        || (self.queen_side
+                            && to == from.left(2)
+                            && self.can_castle_ignoring_checks(false))
+                }
+                _ => false,
             };
+        }
+        if flag != Flag::None {
+            return false;
+        }
 
-            if !(castle_mask.overlaps(&cannot_castle_into)) {
-                add_move(Move {
-                    from: self.friendly_king_square,
-                    to,
-                    flag: Flag::Castle,
-                });
+        let attacks = if self.friendly_knights.get(&from) {
+            Self::knight_attack_bit_board(from)
+        } else {
+            let mut attacks = BitBoard::EMPTY;
+            if self.friendly_diagonal.get(&from) {
+                attacks |=
+                    get_bishop_moves(from, self.occupied_squares & relevant_bishop_blockers(from));
+            }
+            if self.friendly_orthogonal.get(&from) {
+                attacks |=
+                    get_rook_moves(from, self.occupied_squares & relevant_rook_blockers(from));
             }
+            attacks
+        };
+        attacks.get(&to)
+    }
+
+    /// Whether the move is legal, without generating every move.
+    #[must_use]
+    pub fn is_legal(&self, move_data: Move) -> bool {
+        if !self.is_pseudo_legal(move_data) {
+            return false;
         }
-        if self.queen_side {
-            let to = self.friendly_king_square.left(2);
-            let castle_block_mask = if self.white_to_move {
-                BitBoard::new(0b00001110)
+
+        let Move { from, to, flag } = move_data;
+        if from == self.friendly_king_square {
+            return if flag == Flag::Castle {
+                self.can_castle(to.file() > from.file())
             } else {
-                BitBoard::new(0b00001110 << 56)
+                !self.king_danger_bit_board.get(&to)
             };
-
-            if !castle_block_mask.overlaps(&self.occupied_squares) {
-                let castle_mask = if self.white_to_move {
-                    BitBoard::new(0b00001100)
-                } else {
-                    BitBoard::new(0b00001100 << 56)
-                };
-                if !castle_mask.overlaps(&cannot_castle_into) {
-                    add_move(Move {
-                        from: self.friendly_king_square,
-                        to,
-                        flag: Flag::Castle,
-                    });
-                }
-            }
         }
+        if self.is_in_double_check {
+            // Only king can move in double check
+            return false;
+        }
+        if flag == Flag::EnPassant {
+            return pawn_move_generator::is_en_passant_legal(self, from, to);
+        }
+        if !self.check_mask.get(&to) {
+            return false;
+        }
+
+        // A pinned piece can only move along the line between the king and the pinning piece
+        !(self.diagonal_pin_rays | self.orthogonal_pin_rays).get(&from)
+            || get_between_rays(self.friendly_king_square, to).get(&from)
+            || get_between_rays(self.friendly_king_square, from).get(&to)
     }
 }
 
@@ -512,22 +618,38 @@ impl MoveGenerator {
     }
 
     /// Generates all friendly piece moves
-    pub fn generate(&self, mut add_move: impl FnMut(Move), captures_only: bool) {
-        self.gen_king(&mut add_move, captures_only);
+    pub fn generate(&self, add_move: impl FnMut(Move), captures_only: bool) {
+        self.generate_kind(
+            add_move,
+            if captures_only {
+                MoveKind::Captures
+            } else {
+                MoveKind::All
+            },
+        );
+    }
+
+    /// Generates friendly piece moves that do not capture, including castling and promotions by pushing.
+    pub fn generate_quiets(&self, add_move: impl FnMut(Move)) {
+        self.generate_kind(add_move, MoveKind::Quiets);
+    }
+
+    fn generate_kind(&self, mut add_move: impl FnMut(Move), kind: MoveKind) {
+        self.gen_king(&mut add_move, kind);
         if self.is_in_double_check {
             // Only king can move in double check
             return;
         }
 
-        self.gen_pawns(&mut add_move, captures_only);
-        self.gen_knights(&mut add_move, captures_only);
+        self.gen_pawns(&mut add_move, kind);
+        self.gen_knights(&mut add_move, kind);
         let mut friendly_diagonal = self.friendly_diagonal & !self.orthogonal_pin_rays;
         consume_bit_board!(friendly_diagonal, from {
-            self.gen_bishop(from, &mut add_move, captures_only);
+            self.gen_bishop(from, &mut add_move, kind);
         });
         let mut friendly_orthogonal = self.friendly_orthogonal & !self.diagonal_pin_rays;
         consume_bit_board!(friendly_orthogonal, from {
-            self.gen_rook(from, &mut add_move, captures_only);
+            self.gen_rook(from, &mut add_move, kind);
         });
     }
 
@@ -4,7 +4,7 @@ use crate::{
 };
 
 use super::{
-    MoveGenerator,
+    MoveGenerator, MoveKind,
     move_data::{Flag, Move},
     slider_lookup::{get_rook_moves, relevant_rook_blockers},
 };
@@ -82,11 +82,17 @@ pub const fn attack_bit_board(from: Square, white: bool) -> BitBoard {
     }
 }
 
-pub fn generate<F: FnMut(Move)>(
-    move_generator: &MoveGenerator,
-    add_move: &mut F,
-    captures_only: bool,
-) {
+pub fn generate<F: FnMut(Move)>(move_generator: &MoveGenerator, add_move: &mut F, kind: MoveKind) {
+    if kind != MoveKind::Quiets {
+        generate_captures(move_generator, add_move);
+        generate_en_passant(move_generator, add_move);
+    }
+    if kind != MoveKind::Captures {
+        generate_pushes(move_generator, add_move);
+    }
+}
+
+fn generate_captures<F: FnMut(Move)>(move_generator: &MoveGenerator, add_move: &mut F) {
     let promotion_rank = if move_generator.white_to_move {
         BitBoard::RANK_8
     } else {
@@ -169,59 +175,113 @@ pub fn generate<F: FnMut(Move)>(
         promotion_captures!(capture_left_promotions, capture_left_offset);
         captures_no_promotions!(capture_left_no_promotions, capture_left_offset);
     };
+}
 
+fn generate_en_passant<F: FnMut(Move)>(move_generator: &MoveGenerator, add_move: &mut F) {
     if let Some(en_passant_square) = move_generator.en_passant_square {
-        // En passant
-
-        let capture_position =
-            en_passant_square.down(if move_generator.white_to_move { 1 } else { -1 });
-        if move_generator.check_mask.get(&capture_position) {
-            let mut pawns_able_to_en_passant = move_generator.friendly_pawns
-                & {
-                    // Generate attacks for an imaginary enemy pawn at the en passant square
-                    // The up-left and up-right of en_passant_square are squares that we can en passant from
-                    attack_bit_board(en_passant_square, !move_generator.white_to_move)
-                }
-                & !move_generator.orthogonal_pin_rays;
-            'en_passant_check: while pawns_able_to_en_passant.is_not_empty() {
-                let from = pawns_able_to_en_passant.pop_square();
-
-                if move_generator.diagonal_pin_rays.get(&from)
-                    && !move_generator.diagonal_pin_rays.get(&en_passant_square)
-                {
-                    continue;
-                }
-
-                if move_generator.friendly_king_square.rank() == from.rank() {
-                    // Check if en passant will reveal a check
-                    // Not covered by pin rays because enemy pawn was blocking
-                    // Check by pretending the king is a rook to find enemy queens/rooks that are not obstructed
-                    let unblocked = get_rook_moves(
-                        move_generator.friendly_king_square,
-                        (move_generator.occupied_squares
-                            ^ from.bit_board()
-                            ^ capture_position.bit_board())
-                            & relevant_rook_blockers(move_generator.friendly_king_square),
-                    );
-
-                    if unblocked.overlaps(&move_generator.enemy_orthogonal) {
-                        continue 'en_passant_check;
-                    }
-                }
-
+        // Generate attacks for an imaginary enemy pawn at the en passant square
+        // The up-left and up-right of en_passant_square are squares that we can en passant from
+        let mut pawns_able_to_en_passant = move_generator.friendly_pawns
+            & attack_bit_board(en_passant_square, !move_generator.white_to_move);
+        consume_bit_board!(pawns_able_to_en_passant, from {
+            if is_en_passant_legal(move_generator, from, en_passant_square) {
                 add_move(Move {
                     from,
                     to: en_passant_square,
                     flag: Flag::EnPassant,
                 });
             }
+        });
+    }
+}
+
+/// Whether capturing en passant from `from` does not leave the king in check.
+pub fn is_en_passant_legal(
+    move_generator: &MoveGenerator,
+    from: Square,
+    en_passant_square: Square,
+) -> bool {
+    let capture_position =
+        en_passant_square.down(if move_generator.white_to_move { 1 } else { -1 });
+    if !move_generator.check_mask.get(&capture_position)
+        || move_generator.orthogonal_pin_rays.get(&from)
+    {
+        return false;
+    }
+
+    if move_generator.diagonal_pin_rays.get(&from)
+        && !move_generator.diagonal_pin_rays.get(&en_passant_square)
+    {
+        return false;
+    }
+
+    if move_generator.friendly_king_square.rank() == from.rank() {
+        // Check if en passant will reveal a check
+        // Not covered by pin rays because enemy pawn was blocking
+        // Check by pretending the king is a rook to find enemy queens/rooks that are not obstructed
+        let unblocked = get_rook_moves(
+            move_generator.friendly_king_square,
+            (move_generator.occupied_squares ^ from.bit_board() ^ capture_position.bit_board())
+                & relevant_rook_blockers(move_generator.friendly_king_square),
+        );
+
+        if unblocked.overlaps(&move_generator.enemy_orthogonal) {
+            return false;
         }
     }
 
-    if captures_only {
-        return;
+    true
+}
+
+/// Whether a friendly pawn on `move_data.from` could make this move, ignoring pins and checks.
+pub fn is_pseudo_legal(move_generator: &MoveGenerator, move_data: Move) -> bool {
+    let Move { from, to, flag } = move_data;
+    let white_to_move = move_generator.white_to_move;
+    let one_up_offset = if white_to_move { 8 } else { -8 };
+
+    let promotion_rank = if white_to_move {
+        BitBoard::RANK_8
+    } else {
+        BitBoard::RANK_1
+    };
+    if promotion_rank.get(&to) != Flag::PROMOTIONS.contains(&flag) {
+        return false;
+    }
+
+    match flag {
+        Flag::EnPassant => {
+            move_generator.en_passant_square == Some(to)
+                && attack_bit_board(from, white_to_move).get(&to)
+        }
+        Flag::PawnTwoUp => {
+            let starting_rank = if white_to_move {
+                BitBoard::RANK_2
+            } else {
+                BitBoard::RANK_7
+            };
+            starting_rank.get(&from)
+                && move_generator
+                    .empty_squares
+                    .get(&from.offset(one_up_offset))
+                && to == from.offset(one_up_offset * 2)
+                && move_generator.empty_squares.get(&to)
+        }
+        Flag::Castle => false,
+        Flag::None
+        | Flag::QueenPromotion
+        | Flag::RookPromotion
+        | Flag::BishopPromotion
+        | Flag::KnightPromotion => {
+            if move_generator.enemy_piece_bit_board.get(&to) {
+                attack_bit_board(from, white_to_move).get(&to)
+            } else {
+                to == from.offset(one_up_offset) && move_generator.empty_squares.get(&to)
+            }
+        }
     }
+}
 
+fn generate_pushes<F: FnMut(Move)>(move_generator: &MoveGenerator, add_move: &mut F) {
     let one_up_offset = if move_generator.white_to_move { 8 } else { -8 };
 
     let __can_single_push = (move_generator.friendly_pawns & !move_generator.diagonal_pin_rays)
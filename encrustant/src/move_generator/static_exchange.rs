@@ -0,0 +1,235 @@
+use crate::{
+    board::{Board, bit_board::BitBoard, piece::Piece, square::Square},
+    evaluation::eval_data::EvalNumber,
+};
+
+use super::{
+    move_data::{Flag, Move},
+    pawn_move_generator,
+    precomputed::{KING_MOVES_AT_SQUARE, KNIGHT_MOVES_AT_SQUARE},
+    slider_lookup::{
+        get_bishop_moves, get_rook_moves, relevant_bishop_blockers, relevant_rook_blockers,
+    },
+};
+
+/// Values of pawn, knight, bishop, rook, queen, and king used when exchanging pieces.
+pub const SEE_PIECE_VALUES: [EvalNumber; 6] = [100, 300, 300, 500, 900, 0];
+
+const fn piece_value(piece: Piece) -> EvalNumber {
+    SEE_PIECE_VALUES[piece as usize % 6]
+}
+
+fn bishop_attacks(square: Square, occupied: BitBoard) -> BitBoard {
+    get_bishop_moves(square, occupied & relevant_bishop_blockers(square))
+}
+
+fn rook_attacks(square: Square, occupied: BitBoard) -> BitBoard {
+    get_rook_moves(square, occupied & relevant_rook_blockers(square))
+}
+
+/// Returns every piece of both sides attacking `square`, with `occupied` as the blockers.
+fn attackers_to(board: &Board, square: Square, occupied: BitBoard) -> BitBoard {
+    let diagonal = *board.get_bit_board(Piece::WhiteBishop)
+        | *board.get_bit_board(Piece::BlackBishop)
+        | *board.get_bit_board(Piece::WhiteQueen)
+        | *board.get_bit_board(Piece::BlackQueen);
+    let orthogonal = *board.get_bit_board(Piece::WhiteRook)
+        | *board.get_bit_board(Piece::BlackRook)
+        | *board.get_bit_board(Piece::WhiteQueen)
+        | *board.get_bit_board(Piece::BlackQueen);
+
+    // A pawn on `square` would attack the squares that enemy pawns attack it from
+    (pawn_move_generator::attack_bit_board(square, false) & *board.get_bit_board(Piece::WhitePawn))
+        | (pawn_move_generator::attack_bit_board(square, true)
+            & *board.get_bit_board(Piece::BlackPawn))
+        | (KNIGHT_MOVES_AT_SQUARE[square.usize()]
+            & (*board.get_bit_board(Piece::WhiteKnight) | *board.get_bit_board(Piece::BlackKnight)))
+        | (KING_MOVES_AT_SQUARE[square.usize()]
+            & (*board.get_bit_board(Piece::WhiteKing) | *board.get_bit_board(Piece::BlackKing)))
+        | (bishop_attacks(square, occupied) & diagonal)
+        | (rook_attacks(square, occupied) & orthogonal)
+}
+
+fn side_pieces(board: &Board, white: bool) -> [BitBoard; 6] {
+    let pieces = if white {
+        Piece::WHITE_PIECES
+    } else {
+        Piece::BLACK_PIECES
+    };
+    pieces.map(|piece| *board.get_bit_board(piece))
+}
+
+/// Static exchange evaluation.
+///
+/// Returns whether playing `move_data`, then both sides recapturing on its destination with their
+/// least valuable piece for as long as it is worth it, wins at least `threshold`.
+/// Pins are not considered.
+///
+/// # Panics
+///
+/// Will panic if there is no friendly piece at `move_data.from`.
+#[must_use]
+pub fn see(board: &Board, move_data: Move, threshold: EvalNumber) -> bool {
+    if move_data.flag == Flag::Castle {
+        return threshold <= 0;
+    }
+
+    let (from, to) = (move_data.from, move_data.to);
+    let moving_piece = board.friendly_piece_at(from).unwrap();
+
+    let mut occupied = BitBoard::EMPTY;
+    for piece in Piece::ALL_PIECES {
+        occupied |= *board.get_bit_board(piece);
+    }
+
+    let mut swap = if move_data.flag == Flag::EnPassant {
+        occupied ^= to
+            .down(if board.white_to_move { 1 } else { -1 })
+            .bit_board();
+        SEE_PIECE_VALUES[0]
+    } else {
+        board.enemy_piece_at(to).map_or(0, piece_value)
+    } - threshold;
+
+    // The piece left standing on `to`, which can be captured next
+    let mut at_risk = piece_value(moving_piece);
+    if let Some(promotion_piece) = move_data.flag.get_promotion_piece(board.white_to_move) {
+        let promotion_gain = piece_value(promotion_piece) - SEE_PIECE_VALUES[0];
+        swap += promotion_gain;
+        at_risk += promotion_gain;
+    }
+
+    if swap < 0 {
+        return false;
+    }
+
+    swap = at_risk - swap;
+    if swap <= 0 {
+        return true;
+    }
+
+    occupied ^= from.bit_board() | to.bit_board();
+
+    let white_pieces = side_pieces(board, true);
+    let black_pieces = side_pieces(board, false);
+    let diagonal = white_pieces[2] | white_pieces[4] | black_pieces[2] | black_pieces[4];
+    let orthogonal = white_pieces[3] | white_pieces[4] | black_pieces[3] | black_pieces[4];
+
+    let mut attackers = attackers_to(board, to, occupied);
+    let mut white_to_move = !board.white_to_move;
+
+    // Whether the side that made the move wins the exchange
+    let mut result = true;
+
+    loop {
+        attackers &= occupied;
+
+        let pieces = if white_to_move {
+            &white_pieces
+        } else {
+            &black_pieces
+        };
+        let side_attackers =
+            attackers & (pieces[0] | pieces[1] | pieces[2] | pieces[3] | pieces[4] | pieces[5]);
+        if side_attackers.is_empty() {
+            break;
+        }
+
+        result = !result;
+
+        let (piece_index, attacker) = pieces
+            .iter()
+            .enumerate()
+            .find_map(|(piece_index, bit_board)| {
+                let attacker = *bit_board & side_attackers;
+                attacker.is_not_empty().then_some((piece_index, attacker))
+            })
+            .unwrap();
+
+        if piece_index == 5 {
+            // The king can only capture if the other side can not recapture
+            let other_pieces = if white_to_move {
+                &black_pieces
+            } else {
+                &white_pieces
+            };
+            let other_attackers = attackers
+                & (other_pieces[0]
+                    | other_pieces[1]
+                    | other_pieces[2]
+                    | other_pieces[3]
+                    | other_pieces[4]
+                    | other_pieces[5]);
+            if other_attackers.is_not_empty() {
+                result = !result;
+            }
+            break;
+        }
+
+        swap = SEE_PIECE_VALUES[piece_index] - swap;
+        if swap < EvalNumber::from(result) {
+            break;
+        }
+
+        occupied ^= attacker.first_square().bit_board();
+
+        // Capturing can reveal sliders behind
+        if piece_index == 0 || piece_index == 2 || piece_index == 4 {
+            attackers |= bishop_attacks(to, occupied) & diagonal;
+        }
+        if piece_index == 3 || piece_index == 4 {
+            attackers |= rook_attacks(to, occupied) & orthogonal;
+        }
+
+        white_to_move = !white_to_move;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{Board, square::Square},
+        move_generator::{
+            move_data::{Flag, Move},
+            static_exchange::see,
+        },
+    };
+
+    fn capture(fen: &str, from: &str, to: &str) -> (Board, Move) {
+        (
+            Board::from_fen(fen).unwrap(),
+            Move {
+                from: Square::from_notation(from).unwrap(),
+                to: Square::from_notation(to).unwrap(),
+                flag: Flag::None,
+            },
+        )
+    }
+
+    #[test]
+    fn see_works() {
+        // Free pawn
+        let (board, move_data) = capture(
+            "1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1",
+            "e1",
+            "e5",
+        );
+        assert!(see(&board, move_data, 100));
+        assert!(!see(&board, move_data, 101));
+
+        // Defended pawn taken by a knight, then the rook and bishop trade on it
+        let (board, move_data) = capture(
+            "1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1",
+            "d3",
+            "e5",
+        );
+        assert!(!see(&board, move_data, 0));
+        assert!(see(&board, move_data, -200));
+
+        // Queen takes a pawn defended by a pawn
+        let (board, move_data) = capture("4k3/8/3p4/4p3/8/8/8/4Q1K1 w - - 0 1", "e1", "e5");
+        assert!(!see(&board, move_data, 0));
+    }
+}
@@ -61,6 +61,18 @@ impl EncodedMove {
         &Flag::ALL[((self.0 >> 12) & 0b1111) as usize]
     }
 
+    /// Returns the bit-packed move data.
+    #[must_use]
+    pub const fn data(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a move from bit-packed move data returned by `data`.
+    #[must_use]
+    pub const fn from_data(data: u16) -> Self {
+        Self(data)
+    }
+
     /// Returns the bit-packed move data without the flag.
     #[must_use]
     pub const fn without_flag(self) -> u16 {
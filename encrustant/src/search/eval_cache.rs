@@ -0,0 +1,129 @@
+//! Cache of static evaluations, so positions reached by transposition are not evaluated again.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use super::{eval_data::EvalNumber, zobrist::Zobrist};
+
+/// How many transposition table entries there are for each evaluation cache entry.
+const TRANSPOSITION_ENTRIES_PER_ENTRY: usize = 4;
+
+/// Bits of the zobrist key kept in an entry, below the evaluation.
+const KEY_MASK: u64 = (1 << 48) - 1;
+
+/// Lock-free evaluation cache, shareable between search threads.
+///
+/// Each entry is a single atomic holding the evaluation and the lower 48 bits of the zobrist key,
+/// so entries can not be torn by two threads writing at once.
+pub struct EvalCache {
+    entries: Box<[AtomicU64]>,
+}
+
+impl EvalCache {
+    /// Creates an empty evaluation cache with space for `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: (0..capacity.max(1)).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn entry(&self, zobrist_key: Zobrist) -> &AtomicU64 {
+        &self.entries[zobrist_key.distribute(self.entries.len()) as usize]
+    }
+
+    /// Returns the saved static evaluation of the position, if there is one.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn get(&self, zobrist_key: Zobrist) -> Option<EvalNumber> {
+        let data = self.entry(zobrist_key).load(Ordering::Relaxed);
+        if data != 0 && (data ^ zobrist_key.value()) & KEY_MASK == 0 {
+            Some(EvalNumber::from((data >> 48) as u16 as i16))
+        } else {
+            None
+        }
+    }
+
+    /// Saves the static evaluation of the position, replacing whatever was in its entry.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn store(&self, zobrist_key: Zobrist, static_eval: EvalNumber) {
+        // Evaluations too large to pack are not cached
+        if let Ok(static_eval) = i16::try_from(static_eval) {
+            self.entry(zobrist_key).store(
+                (zobrist_key.value() & KEY_MASK) | (u64::from(static_eval as u16) << 48),
+                Ordering::Relaxed,
+            );
+        }
+    }
+
+    /// Hints to the processor that the entry of the position will be read soon.
+    pub fn prefetch(&self, zobrist_key: Zobrist) {
+        #[cfg(target_feature = "sse")]
+        {
+            use core::arch::x86_64::{_MM_HINT_NTA, _mm_prefetch};
+            unsafe {
+                _mm_prefetch::<{ _MM_HINT_NTA }>(
+                    core::ptr::from_ref(self.entry(zobrist_key)).cast::<i8>(),
+                );
+            }
+        }
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        {
+            use core::arch::aarch64::{_PREFETCH_LOCALITY0, _PREFETCH_READ, _prefetch};
+            unsafe {
+                _prefetch::<_PREFETCH_READ, _PREFETCH_LOCALITY0>(
+                    core::ptr::from_ref(self.entry(zobrist_key)).cast::<i8>(),
+                );
+            }
+        }
+        #[cfg(not(any(
+            target_feature = "sse",
+            target_arch = "aarch64",
+            target_arch = "arm64ec"
+        )))]
+        let _ = zobrist_key;
+    }
+
+    /// Empties every entry.
+    pub fn clear(&self) {
+        for entry in &self.entries {
+            entry.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// How many bytes one evaluation cache entry takes.
+const MEMORY_OF_ONE_ENTRY_IN_BYTES: usize = core::mem::size_of::<AtomicU64>();
+
+/// Memory the evaluation cache takes for each transposition table entry, so both fit in the `Hash` option.
+pub const MEMORY_PER_TRANSPOSITION_ENTRY_IN_BYTES: usize =
+    MEMORY_OF_ONE_ENTRY_IN_BYTES / TRANSPOSITION_ENTRIES_PER_ENTRY;
+
+/// Returns how many entries the evaluation cache has next to a transposition table of `transposition_capacity`.
+#[must_use]
+pub const fn capacity(transposition_capacity: usize) -> usize {
+    transposition_capacity / TRANSPOSITION_ENTRIES_PER_ENTRY
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{eval_cache::EvalCache, zobrist::Zobrist};
+
+    #[test]
+    fn only_the_same_position_is_found() {
+        let eval_cache = EvalCache::new(1);
+        let mut zobrist_key = Zobrist::EMPTY;
+        zobrist_key.xor_piece(0, 1);
+        let mut other_key = Zobrist::EMPTY;
+        other_key.xor_piece(0, 2);
+
+        eval_cache.store(zobrist_key, -1234);
+        assert_eq!(eval_cache.get(zobrist_key), Some(-1234));
+        assert_eq!(eval_cache.get(other_key), None);
+
+        // Both positions share the only entry
+        eval_cache.store(other_key, 56);
+        assert_eq!(eval_cache.get(zobrist_key), None);
+        assert_eq!(eval_cache.get(other_key), Some(56));
+    }
+}
@@ -1,47 +1,69 @@
 //! Finds the best outcome in a chess position.
 
 pub mod encoded_move;
+mod eval_cache;
 mod move_ordering;
+mod pawn_table;
 pub mod pv;
 mod repetition_table;
 pub mod search_params;
+mod search_stack;
 pub mod time_manager;
 pub mod transposition;
 
 /// Zobrist key.
 pub mod zobrist;
 
+use core::num::NonZeroU16;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use eval_cache::EvalCache;
+use pawn_table::PawnTable;
 use pv::Pv;
 use search_params::{DEFAULT_TUNABLES, Tunable};
+use search_stack::SearchStack;
 use time_manager::TimeManager;
+use transposition::TranspositionTable;
 use zobrist::Zobrist;
 
 use crate::{
     board::{Board, game_state::GameState, piece::Piece, square::Square},
     evaluation::{
-        Eval,
+        Eval, endgame,
         eval_data::{self, EvalNumber},
+        nnue::{Accumulator, Network},
     },
     move_generator::{
         MoveGenerator,
         move_data::{Flag, Move},
+        static_exchange::see,
     },
+    tablebase::{Tablebase, Wdl},
 };
 
 use self::{
     encoded_move::EncodedMove,
-    move_ordering::MoveOrderer,
+    move_ordering::{MoveOrderer, MovePicker},
     repetition_table::RepetitionTable,
     transposition::{NodeType, NodeValue},
 };
 
 pub type Ply = u8;
 
+/// Deepest ply searched from the root, so the search stack and principal variation can not overflow.
+const MAX_PLY: Ply = Ply::MAX - 2;
+
 /// Score of having checkmated the opponent.
 pub const IMMEDIATE_CHECKMATE_SCORE: EvalNumber = EvalNumber::MAX - 1;
 
 const CHECKMATE_SCORE: EvalNumber = IMMEDIATE_CHECKMATE_SCORE - (Ply::MAX as EvalNumber);
 
+/// Score of a position the tablebase says is won, lowered by the ply it is found at.
+const TABLEBASE_WIN_SCORE: EvalNumber = CHECKMATE_SCORE - 1 - (Ply::MAX as EvalNumber);
+
 const USE_STATIC_NULL_MOVE_PRUNING: bool = true;
 const USE_NULL_MOVE_PRUNING: bool = true;
 const USE_LATE_MOVE_REDUCTION: bool = true;
@@ -50,6 +72,11 @@ const USE_PVS: bool = true;
 const USE_KILLER_MOVE: bool = true;
 const USE_ASPIRATION_WINDOWS: bool = true;
 const USE_FUTILITY_PRUNING: bool = true;
+const USE_QUIESCENCE_CHECKS: bool = false;
+const USE_SINGULAR_EXTENSIONS: bool = true;
+const USE_MATE_DISTANCE_PRUNING: bool = true;
+const USE_RAZORING: bool = true;
+const USE_PROBCUT: bool = true;
 
 #[cfg(not(feature = "spsa"))]
 macro_rules! param {
@@ -64,8 +91,32 @@ macro_rules! param {
     };
 }
 
+/// Most that a history entry can reach.
+const MAX_HISTORY: i32 = 16384;
+
+/// Returns how much a history entry changes by, so that it moves towards the bonus but never passes [`MAX_HISTORY`].
+#[allow(clippy::cast_possible_truncation)]
+const fn history_gravity(current_value: i16, history_bonus: i32) -> i16 {
+    (history_bonus - (current_value as i32 * history_bonus.abs() / MAX_HISTORY)) as i16
+}
+
+/// Whether the hash move is the only good move in a position.
+enum Singularity {
+    /// The hash move was not checked, or is good but not the only good move.
+    Unknown,
+
+    /// Every other move is much worse, so the hash move is searched deeper.
+    Singular,
+
+    /// Another move is also good enough to fail high, so the hash move is searched less deep.
+    NotSingular,
+
+    /// Another move fails high on its own, so the node can be cut off with this score.
+    MultiCut(EvalNumber),
+}
+
 /// Search info at a depth.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct DepthSearchInfo {
     /// Depth searched at.
     pub depth: Ply,
@@ -78,6 +129,12 @@ pub struct DepthSearchInfo {
 
     /// How many times `make_move` was called in search
     pub node_count: u64,
+
+    /// How many positions were found in the tablebase.
+    pub tablebase_hits: u64,
+
+    /// Which line this is when searching multiple principal variations, starting from 1 for the best.
+    pub multi_pv: usize,
 }
 
 const PAWN_CORRECTION_HISTORY_LENGTH: usize = 8192;
@@ -106,7 +163,10 @@ pub struct Search {
 
     repetition_table: RepetitionTable,
 
-    transposition_table: Vec<Option<NodeValue>>,
+    transposition_table: Arc<TranspositionTable>,
+
+    /// Static evaluations of positions, shared like the transposition table.
+    eval_cache: Arc<EvalCache>,
 
     quiet_history: Box<[[i16; 64 * 64]; 2]>,
     capture_history: Box<[[[i16; 6]; 64]; 12]>, // Inner table length is 6 because outer table already gives information about the piece colour
@@ -114,16 +174,58 @@ pub struct Search {
     pawn_correction_history: Box<[[i16; PAWN_CORRECTION_HISTORY_LENGTH]; 2]>,
     minor_piece_correction_history: Box<[[i16; MINOR_PIECE_CORRECTION_HISTORY_LENGTH]; 2]>,
 
-    eval_history: [EvalNumber; 256],
+    /// Evaluated pawn structures, so they are not evaluated again at every node.
+    pawn_table: PawnTable,
 
-    killer_moves: [EncodedMove; 64],
+    /// Static evaluation, killer move, and the move being searched at each ply from the root.
+    stack: SearchStack,
+
+    /// History of quiet moves, indexed by the piece and destination of the previous move, then of this move.
+    /// Used for the moves one and two plies ago.
+    continuation_history: Box<[[[[i16; 64]; 12]; 64]; 12]>,
+
+    /// Quiet move that last caused a beta cutoff in reply to a piece moving to a square.
+    counter_moves: [[EncodedMove; 64]; 12],
 
     search_state: SearchState,
 
     pub pv: Pv,
     pub highest_depth: Ply,
 
-    node_count: u64,
+    /// Depth of the current iteration of iterative deepening.
+    root_depth: Ply,
+
+    /// Only written to by the thread running this search, but read by others.
+    node_count: Arc<AtomicU64>,
+
+    /// 0 for the main search, otherwise a helper search of lazy SMP.
+    thread_index: usize,
+
+    tablebase: Option<Arc<Tablebase>>,
+
+    /// Most pieces a position can have to be probed in the tablebase.
+    tablebase_probe_limit: usize,
+
+    /// Tablebase probes that found the position, read by the UCI thread for `tbhits`.
+    tablebase_hits: Arc<AtomicU64>,
+
+    /// Moves the root is limited to, or empty to search every move.
+    root_moves: Vec<EncodedMove>,
+
+    /// Moves the user limited the root to with `go searchmoves`, or empty to allow every move.
+    requested_root_moves: Vec<EncodedMove>,
+
+    /// Root moves of lines already found at this depth when searching multiple principal variations.
+    excluded_root_moves: Vec<EncodedMove>,
+
+    /// Nodes searched under each root move since the search started, indexed by from and to square.
+    root_move_nodes: Box<[u64; 64 * 64]>,
+
+    /// Evaluates positions instead of the piece square tables, if set.
+    network: Option<Arc<Network>>,
+
+    /// Accumulator of the network for each position from the root, so unmaking a move is a pop.
+    accumulators: Vec<Accumulator>,
 
     #[cfg(feature = "spsa")]
     tunable: Tunable,
@@ -136,6 +238,40 @@ impl Search {
         board: Board,
         transposition_capacity: usize,
         #[cfg(feature = "spsa")] tunable: Tunable,
+    ) -> Self {
+        Self::with_transposition_table(
+            board,
+            Arc::new(TranspositionTable::new(transposition_capacity)),
+            Arc::new(EvalCache::new(eval_cache::capacity(transposition_capacity))),
+            0,
+            #[cfg(feature = "spsa")]
+            tunable,
+        )
+    }
+
+    /// Create a helper search which shares the transposition table and evaluation cache of this search.
+    #[must_use]
+    pub fn new_helper(&self, thread_index: usize) -> Self {
+        assert_ne!(thread_index, 0, "Thread index 0 is the main search");
+        let mut helper = Self::with_transposition_table(
+            self.board.clone(),
+            self.transposition_table.clone(),
+            self.eval_cache.clone(),
+            thread_index,
+            #[cfg(feature = "spsa")]
+            self.tunable,
+        );
+        helper.set_tablebase(self.tablebase.clone(), self.tablebase_probe_limit);
+        helper.set_network(self.network.clone());
+        helper
+    }
+
+    fn with_transposition_table(
+        board: Board,
+        transposition_table: Arc<TranspositionTable>,
+        eval_cache: Arc<EvalCache>,
+        thread_index: usize,
+        #[cfg(feature = "spsa")] tunable: Tunable,
     ) -> Self {
         let (total_middle_game_score, total_end_game_score) = Eval::raw_evaluate(&board);
         let position_zobrist_key = Zobrist::compute(&board);
@@ -146,9 +282,9 @@ impl Search {
 
             repetition_table: RepetitionTable::new(),
 
-            transposition_table: vec![None; transposition_capacity],
+            transposition_table,
+            eval_cache,
 
-            killer_moves: [EncodedMove::NONE; 64],
             quiet_history: vec![[0; 64 * 64]; 2].try_into().unwrap(),
             capture_history: vec![[[0; 6]; 64]; 12].try_into().unwrap(),
 
@@ -159,7 +295,12 @@ impl Search {
                 .try_into()
                 .unwrap(),
 
-            eval_history: [0; 256],
+            pawn_table: PawnTable::new(),
+
+            stack: SearchStack::new(),
+            // SAFETY: Zero is a valid history score. Allocated zeroed, because a row is too large for the stack.
+            continuation_history: unsafe { Box::new_zeroed().assume_init() },
+            counter_moves: [[EncodedMove::NONE; 64]; 12],
 
             search_state: SearchState {
                 total_middle_game_score,
@@ -170,14 +311,63 @@ impl Search {
 
             pv: Pv::new(),
             highest_depth: 0,
+            root_depth: 0,
+
+            node_count: Arc::new(AtomicU64::new(0)),
+            thread_index,
 
-            node_count: 0,
+            tablebase: None,
+            tablebase_probe_limit: 0,
+            tablebase_hits: Arc::new(AtomicU64::new(0)),
+            root_moves: Vec::new(),
+            requested_root_moves: Vec::new(),
+            excluded_root_moves: Vec::new(),
+            root_move_nodes: vec![0; 64 * 64].try_into().unwrap(),
+
+            network: None,
+            accumulators: Vec::new(),
 
             #[cfg(feature = "spsa")]
             tunable,
         }
     }
 
+    /// Sets the tablebase to probe positions with at most `probe_limit` pieces in.
+    ///
+    /// Helper searches made before this will not use the tablebase.
+    pub fn set_tablebase(&mut self, tablebase: Option<Arc<Tablebase>>, probe_limit: usize) {
+        self.tablebase = tablebase;
+        self.tablebase_probe_limit = probe_limit;
+    }
+
+    /// Sets the network to evaluate positions with, or uses the piece square tables if it is `None`.
+    pub fn set_network(&mut self, network: Option<Arc<Network>>) {
+        let changed = match (&self.network, &network) {
+            (Some(old), Some(new)) => !Arc::ptr_eq(old, new),
+            (old, new) => old.is_some() != new.is_some(),
+        };
+
+        // Helpers share the cache and are always given the network of the main search
+        if changed && self.thread_index == 0 {
+            self.eval_cache.clear();
+        }
+        self.network = network;
+        self.refresh_accumulators();
+    }
+
+    /// Calculates the accumulator of the current position from scratch.
+    fn refresh_accumulators(&mut self) {
+        self.accumulators.clear();
+        if let Some(network) = &self.network {
+            self.accumulators.push(network.accumulator(&self.board));
+        }
+    }
+
+    /// Limits the root to `search_moves`, or allows every move if it is empty.
+    pub fn set_search_moves(&mut self, search_moves: &[Move]) {
+        self.requested_root_moves = search_moves.iter().copied().map(EncodedMove::new).collect();
+    }
+
     /// Skips the turn
     pub const fn make_null_move(&mut self) -> ExtendedState {
         let old_search_state = self.search_state;
@@ -194,6 +384,9 @@ impl Search {
         }
         self.board.game_state.en_passant_square = None;
 
+        // Positions before the null move are not in the repetition table
+        self.board.game_state.half_move_clock = 0;
+
         ExtendedState {
             search_state: old_search_state,
             game_state: old_game_state,
@@ -207,9 +400,12 @@ impl Search {
         self.board.white_to_move = !self.board.white_to_move;
     }
 
-    /// Sets an empty transposition table with the new capacity.
+    /// Sets an empty transposition table with the new capacity, and an empty evaluation cache sized to match.
+    ///
+    /// Helper searches made before this will no longer share the transposition table.
     pub fn resize_transposition_table(&mut self, transposition_capacity: usize) {
-        self.transposition_table = vec![None; transposition_capacity];
+        self.transposition_table = Arc::new(TranspositionTable::new(transposition_capacity));
+        self.eval_cache = Arc::new(EvalCache::new(eval_cache::capacity(transposition_capacity)));
     }
 
     /// Returns the current board.
@@ -230,15 +426,21 @@ impl Search {
         self.search_state.total_end_game_score = total_end_game_score;
         self.search_state.position_zobrist_key = position_zobrist_key;
         self.search_state.pawn_zobrist_key = pawn_zobrist_key;
+        self.refresh_accumulators();
     }
 
     /// Another search.
     pub fn clear_for_new_search(&mut self) {
-        // Don't need to clear `eval_history` because each ply is overwritten before they can be read
-
-        self.node_count = 0;
+        self.node_count.store(0, Ordering::Relaxed);
+        self.tablebase_hits.store(0, Ordering::Relaxed);
+        self.pawn_table.clear_statistics();
         self.highest_depth = 0;
-        self.killer_moves.fill(EncodedMove::NONE);
+        self.root_move_nodes.fill(0);
+        if self.thread_index == 0 {
+            // Helpers share the table, so it should only age once
+            self.transposition_table.new_search();
+        }
+        self.stack.clear();
 
         for value in &mut self.quiet_history[0] {
             *value /= param!(self).history_decay;
@@ -263,21 +465,60 @@ impl Search {
 
         self.quiet_history[0].fill(0);
         self.quiet_history[1].fill(0);
+        for piece_history in self.continuation_history.iter_mut() {
+            for square_history in piece_history {
+                for history in square_history {
+                    history.fill(0);
+                }
+            }
+        }
+        for counter_moves in &mut self.counter_moves {
+            counter_moves.fill(EncodedMove::NONE);
+        }
 
-        self.transposition_table.fill(None);
+        self.transposition_table.clear();
+        self.eval_cache.clear();
     }
 
     #[must_use]
-    fn quiescence_search(&mut self, mut alpha: EvalNumber, beta: EvalNumber) -> EvalNumber {
-        let pawn_index = self
-            .pawn_zobrist_key()
-            .modulo(PAWN_CORRECTION_HISTORY_LENGTH as u64);
-        let minor_piece_index = self
-            .minor_piece_zobrist_key()
-            .modulo(MINOR_PIECE_CORRECTION_HISTORY_LENGTH as u64);
+    fn quiescence_search(
+        &mut self,
+        ply_from_root: Ply,
+        search_checks: bool,
+        mut alpha: EvalNumber,
+        beta: EvalNumber,
+    ) -> EvalNumber {
+        if ply_from_root >= MAX_PLY {
+            return self.corrected_static_evaluate();
+        }
 
-        let mut best_score =
-            self.get_correction(self.static_evaluate(), pawn_index, minor_piece_index);
+        let zobrist_key = self.position_zobrist_key();
+        let original_alpha = alpha;
+
+        let hash_move =
+            if let Some(saved) = self.transposition_table.get(zobrist_key, ply_from_root) {
+                // Every saved depth is at least as deep as quiescence search
+                if match saved.node_type {
+                    NodeType::Exact => true,
+                    NodeType::Beta => saved.value >= beta,
+                    NodeType::Alpha => saved.value <= alpha,
+                } {
+                    return saved.value;
+                }
+                saved.transposition_move
+            } else {
+                EncodedMove::NONE
+            };
+
+        let move_generator = MoveGenerator::new(&self.board);
+        let is_in_check = move_generator.is_in_check();
+
+        let mut best_score = if is_in_check {
+            // Standing pat is not allowed, because every evasion could be losing
+            -EvalNumber::MAX
+        } else {
+            self.corrected_static_evaluate()
+        };
 
         if best_score > alpha {
             alpha = best_score;
@@ -287,27 +528,42 @@ impl Search {
             }
         }
 
-        let move_generator = MoveGenerator::new(&self.board);
-        let (mut move_guesses, move_count) =
-            MoveOrderer::get_move_guesses_captures_only(self, &move_generator);
-        let mut index = 0;
-        while index != move_count {
+        // Every evasion is searched when in check, otherwise only captures
+        let (mut all_move_guesses, mut capture_guesses);
+        let move_count;
+        let move_guesses: &mut [_] = if is_in_check {
+            (all_move_guesses, move_count) =
+                MoveOrderer::get_move_guesses(self, &move_generator, hash_move, ply_from_root);
+            &mut all_move_guesses
+        } else {
+            (capture_guesses, move_count) =
+                MoveOrderer::get_move_guesses_captures_only(self, &move_generator);
+            &mut capture_guesses
+        };
+
+        if is_in_check && move_count == 0 {
+            return -IMMEDIATE_CHECKMATE_SCORE + EvalNumber::from(ply_from_root);
+        }
+
+        let mut best_move = EncodedMove::NONE;
+        for index in 0..move_count {
             let move_data = unsafe {
-                // SAFETY: `get_move_guesses_captures_only` guarantees that `move_guesses[0..move_count]` are initialised.
-                // `index` can not be higher than `move_count`, due to the loop condition.
+                // SAFETY: `get_move_guesses` and `get_move_guesses_captures_only` guarantee that `move_guesses[0..move_count]` are initialised.
+                // `index` is always lower than `move_count`, due to the range.
 
-                MoveOrderer::put_highest_guessed_move(&mut move_guesses, index, move_count)
+                MoveOrderer::put_highest_guessed_move(move_guesses, index, move_count)
             }
-            .move_data
-            .decode();
+            .move_data;
 
-            let old_state = self.make_move::<false>(&move_data);
-            self.node_count += 1;
-            let score = -self.quiescence_search(-beta, -alpha);
-            self.unmake_move(&move_data, &old_state);
+            if !is_in_check && !see(&self.board, move_data.decode(), 0) {
+                // Losing captures are unlikely to raise alpha
+                continue;
+            }
 
+            let score = self.quiescence_search_move(ply_from_root, move_data, alpha, beta);
             if score > best_score {
                 best_score = score;
+                best_move = move_data;
                 if score > alpha {
                     alpha = score;
 
@@ -316,13 +572,115 @@ impl Search {
                     }
                 }
             }
+        }
 
-            index += 1;
+        if USE_QUIESCENCE_CHECKS && search_checks && !is_in_check && best_score < beta {
+            for move_data in self.safe_quiet_checks(&move_generator) {
+                let score = self.quiescence_search_move(ply_from_root, move_data, alpha, beta);
+                if score > best_score {
+                    best_score = score;
+                    best_move = move_data;
+                    if score > alpha {
+                        alpha = score;
+
+                        if score >= beta {
+                            break;
+                        }
+                    }
+                }
+            }
         }
+
+        self.transposition_table.store(
+            zobrist_key,
+            NodeValue {
+                ply_remaining: 0,
+                node_type: if best_score >= beta {
+                    NodeType::Beta
+                } else if best_score > original_alpha {
+                    NodeType::Exact
+                } else {
+                    NodeType::Alpha
+                },
+                value: best_score,
+                transposition_move: best_move,
+            },
+            ply_from_root,
+        );
+
         best_score
     }
 
+    /// Returns the static evaluation adjusted by the correction histories.
+    fn corrected_static_evaluate(&mut self) -> EvalNumber {
+        let pawn_index = self
+            .pawn_zobrist_key()
+            .modulo(PAWN_CORRECTION_HISTORY_LENGTH as u64);
+        let minor_piece_index = self
+            .minor_piece_zobrist_key()
+            .modulo(MINOR_PIECE_CORRECTION_HISTORY_LENGTH as u64);
+
+        let static_eval = self.static_evaluate();
+        self.get_correction(static_eval, pawn_index, minor_piece_index)
+    }
+
+    /// Returns the quiet moves that give check without losing material.
+    fn safe_quiet_checks(&mut self, move_generator: &MoveGenerator) -> Vec<EncodedMove> {
+        let mut quiet_moves = Vec::new();
+        move_generator.generate(
+            |move_data| {
+                if move_data.flag != Flag::EnPassant
+                    && self.board.enemy_piece_at(move_data.to).is_none()
+                    && see(&self.board, move_data, 0)
+                {
+                    quiet_moves.push(move_data);
+                }
+            },
+            false,
+        );
+
+        let mut quiet_checks = Vec::new();
+        for move_data in quiet_moves {
+            let old_state = self.make_move::<false>(&move_data);
+            let gives_check = MoveGenerator::calculate_is_in_check(&self.board);
+            self.unmake_move(&move_data, &old_state);
+            if gives_check {
+                quiet_checks.push(EncodedMove::new(move_data));
+            }
+        }
+        quiet_checks
+    }
+
+    /// Plays a move in quiescence search and returns its score.
+    fn quiescence_search_move(
+        &mut self,
+        ply_from_root: Ply,
+        move_data: EncodedMove,
+        alpha: EvalNumber,
+        beta: EvalNumber,
+    ) -> EvalNumber {
+        let frame = &mut self.stack[ply_from_root];
+        frame.current_move = move_data;
+
+        let move_data = move_data.decode();
+        frame.moved_piece = self
+            .board
+            .friendly_piece_at(move_data.from)
+            .map(|moving_piece| (moving_piece, move_data.to));
+
+        let old_state = self.make_move::<false>(&move_data);
+        self.increment_node_count();
+        let score = -self.quiescence_search(ply_from_root.saturating_add(1), false, -beta, -alpha);
+        self.unmake_move(&move_data, &old_state);
+        score
+    }
+
     fn evaluation_remove_piece(&mut self, piece: Piece, square: Square) {
+        if let Some(network) = &self.network {
+            network.remove_piece(self.accumulators.last_mut().unwrap(), piece, square);
+            return;
+        }
+
         let is_white = match piece {
             Piece::WhitePawn
             | Piece::WhiteKnight
@@ -359,6 +717,11 @@ impl Search {
         }
     }
     fn evaluation_add_piece(&mut self, piece: Piece, square: Square) {
+        if let Some(network) = &self.network {
+            network.add_piece(self.accumulators.last_mut().unwrap(), piece, square);
+            return;
+        }
+
         let is_white = match piece {
             Piece::WhitePawn
             | Piece::WhiteKnight
@@ -414,7 +777,38 @@ impl Search {
     }
 
     #[must_use]
-    pub fn static_evaluate(&self) -> EvalNumber {
+    pub fn static_evaluate(&mut self) -> EvalNumber {
+        let zobrist_key = self.search_state.position_zobrist_key;
+        if let Some(static_eval) = self.eval_cache.get(zobrist_key) {
+            #[cfg(debug_assertions)]
+            {
+                assert_eq!(static_eval, self.uncached_static_evaluate());
+            };
+
+            return static_eval;
+        }
+
+        let static_eval = self.uncached_static_evaluate();
+        self.eval_cache.store(zobrist_key, static_eval);
+        static_eval
+    }
+
+    fn uncached_static_evaluate(&mut self) -> EvalNumber {
+        if let Some(network) = &self.network {
+            let accumulator = self.accumulators.last().unwrap();
+            let static_eval = network.evaluate(accumulator, self.board.white_to_move);
+
+            #[cfg(debug_assertions)]
+            {
+                assert_eq!(
+                    static_eval,
+                    network.evaluate(&network.accumulator(&self.board), self.board.white_to_move)
+                );
+            };
+
+            return static_eval;
+        }
+
         let phases = eval_data::PHASES;
         #[rustfmt::skip]
         let total_phase = {
@@ -424,14 +818,22 @@ impl Search {
             + phases[3] * 4
             + phases[4] * 2
         };
-        let phase = Eval::get_phase(&self.board, &phases);
-
-        let static_eval = Eval::calculate_score(
-            phase,
-            total_phase,
-            self.search_state.total_middle_game_score,
-            self.search_state.total_end_game_score,
-        ) * if self.board.white_to_move { 1 } else { -1 };
+        let static_eval = endgame::evaluate(&self.board, || {
+            let phase = Eval::get_phase(&self.board, &phases);
+
+            // Piece-square tables are updated incrementally, the other terms are not
+            let pawn_structure = self
+                .pawn_table
+                .probe(self.search_state.pawn_zobrist_key, &self.board);
+            let (middle_game_terms_score, end_game_terms_score) =
+                Eval::raw_evaluate_terms_with_pawn_structure(&self.board, pawn_structure);
+            Eval::calculate_score(
+                phase,
+                total_phase,
+                self.search_state.total_middle_game_score + middle_game_terms_score,
+                self.search_state.total_end_game_score + end_game_terms_score,
+            )
+        }) * if self.board.white_to_move { 1 } else { -1 };
 
         #[cfg(debug_assertions)]
         {
@@ -447,6 +849,10 @@ impl Search {
         debug_assert!(Zobrist::compute(&self.board) == self.position_zobrist_key());
 
         let search_state = self.search_state;
+        if self.network.is_some() {
+            // Updated from a copy of the accumulator before the move
+            self.accumulators.push(*self.accumulators.last().unwrap());
+        }
 
         self.search_state.position_zobrist_key.flip_side_to_move();
 
@@ -591,30 +997,9 @@ impl Search {
         }
 
         if PREFETCH {
-            #[cfg(target_feature = "sse")]
-            {
-                use core::arch::x86_64::{_MM_HINT_NTA, _mm_prefetch};
-                let index =
-                    self.position_zobrist_key()
-                        .distribute(self.transposition_table.len()) as usize;
-                unsafe {
-                    _mm_prefetch::<{ _MM_HINT_NTA }>(
-                        self.transposition_table.as_ptr().add(index).cast::<i8>(),
-                    );
-                }
-            }
-            #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
-            {
-                use core::arch::aarch64::{_PREFETCH_LOCALITY0, _PREFETCH_READ, _prefetch};
-                let index =
-                    self.position_zobrist_key()
-                        .distribute(self.transposition_table.len()) as usize;
-                unsafe {
-                    _prefetch::<_PREFETCH_READ, _PREFETCH_LOCALITY0>(
-                        self.transposition_table.as_ptr().add(index).cast::<i8>(),
-                    );
-                }
-            }
+            self.transposition_table
+                .prefetch(self.position_zobrist_key());
+            self.eval_cache.prefetch(self.position_zobrist_key());
         }
 
         let game_state = self.board.make_move(move_data);
@@ -652,6 +1037,9 @@ impl Search {
     /// Unmakes a move and updates the evaluation.
     pub fn unmake_move(&mut self, move_data: &Move, old_state: &ExtendedState) {
         self.search_state = old_state.search_state;
+        if self.network.is_some() {
+            self.accumulators.pop();
+        }
         self.board.unmake_move(move_data, &old_state.game_state);
 
         debug_assert!(Zobrist::compute(&self.board) == self.position_zobrist_key());
@@ -669,7 +1057,7 @@ impl Search {
         allow_null_move: bool,
 
         mut alpha: EvalNumber,
-        beta: EvalNumber,
+        mut beta: EvalNumber,
     ) -> EvalNumber {
         if ply_from_root > self.highest_depth {
             self.highest_depth = ply_from_root;
@@ -680,52 +1068,122 @@ impl Search {
         // Get the zobrist key
         let zobrist_key = self.position_zobrist_key();
 
-        // Check for repetition
         if ply_from_root != 0 {
-            if self.repetition_table.contains(zobrist_key) {
+            let half_move_clock = self.board.game_state.half_move_clock;
+
+            // Check for repetition
+            if self
+                .repetition_table
+                .is_repetition(zobrist_key, half_move_clock, ply_from_root)
+            {
                 return 0;
             }
             if self.board.is_insufficient_material() {
                 return 0;
             }
-        }
 
-        // Turn zobrist key into an index into the transposition table
-        let zobrist_index = zobrist_key.distribute(self.transposition_table.len()) as usize;
+            // Fifty-move rule
+            if half_move_clock >= 100 {
+                // Checkmate on the last move still wins
+                let move_generator = MoveGenerator::new(&self.board);
+                if move_generator.is_in_check() {
+                    let mut has_legal_move = false;
+                    move_generator.generate(&mut |_| has_legal_move = true, false);
+                    if !has_legal_move {
+                        return -IMMEDIATE_CHECKMATE_SCORE + EvalNumber::from(ply_from_root);
+                    }
+                }
+                return 0;
+            }
+        }
 
         // This is the best move in this position according to previous searches
         let mut hash_move = EncodedMove::NONE;
 
+        let excluded_move = self.stack[ply_from_root].excluded_move;
+
+        // Some moves are skipped, so the score is not the true score of the position
+        let excluding_moves = !excluded_move.is_none()
+            || (ply_from_root == 0
+                && (!self.root_moves.is_empty() || !self.excluded_root_moves.is_empty()));
+
         // Check if this is a pv node
         let is_not_pv_node = alpha + 1 == beta;
 
+        if USE_MATE_DISTANCE_PRUNING && ply_from_root != 0 {
+            // Mate distance pruning
+            // Neither side can do better than mating right now, or worse than being mated right now
+            alpha = alpha.max(-IMMEDIATE_CHECKMATE_SCORE + EvalNumber::from(ply_from_root));
+            beta = beta.min(IMMEDIATE_CHECKMATE_SCORE - EvalNumber::from(ply_from_root) - 1);
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+
         // Get value from transposition table
-        let saved = if let Some(saved) = self.transposition_table[zobrist_index] {
-            // Check if it's actually the same position
-            if saved.zobrist_key_32 == zobrist_key.lower_u32() {
-                // Check if the saved depth is as high as the depth now
-                if saved.ply_remaining >= ply_remaining {
-                    let node_type = &saved.node_type;
-                    if match node_type {
-                        NodeType::Exact => is_not_pv_node,
-                        NodeType::Beta => saved.value >= beta,
-                        NodeType::Alpha => saved.value <= alpha,
-                    } {
+        let saved = self.transposition_table.get(zobrist_key, ply_from_root);
+        if let Some(saved) = saved {
+            // Check if the saved depth is as high as the depth now
+            if saved.ply_remaining >= ply_remaining && !excluding_moves {
+                let node_type = &saved.node_type;
+                if match node_type {
+                    NodeType::Exact => is_not_pv_node,
+                    NodeType::Beta => saved.value >= beta,
+                    NodeType::Alpha => saved.value <= alpha,
+                } {
+                    // A different position with the same key could have saved this
+                    if !saved.transposition_move.is_none()
+                        && Self::is_valid_move(
+                            &MoveGenerator::new(&self.board),
+                            saved.transposition_move,
+                        )
+                    {
                         self.pv.update_move(ply_from_root, saved.transposition_move);
-
-                        return saved.value;
                     }
+
+                    return saved.value;
                 }
+            }
 
-                hash_move = saved.transposition_move;
+            hash_move = saved.transposition_move;
+        }
 
-                Some(saved)
-            } else {
-                None
+        // Tablebases ignore the fifty-move counter, so only probe right after it was reset
+        if ply_from_root != 0
+            && excluded_move.is_none()
+            && self.board.game_state.half_move_clock == 0
+            && let Some(wdl) = self.probe_tablebase_wdl()
+        {
+            let (value, node_type) = match wdl {
+                Wdl::Win => (
+                    TABLEBASE_WIN_SCORE - EvalNumber::from(ply_from_root),
+                    NodeType::Beta,
+                ),
+                Wdl::Loss => (
+                    -TABLEBASE_WIN_SCORE + EvalNumber::from(ply_from_root),
+                    NodeType::Alpha,
+                ),
+                Wdl::CursedWin | Wdl::Draw | Wdl::BlessedLoss => (0, NodeType::Exact),
+            };
+            if match node_type {
+                NodeType::Exact => true,
+                NodeType::Beta => value >= beta,
+                NodeType::Alpha => value <= alpha,
+            } {
+                self.transposition_table.store(
+                    zobrist_key,
+                    NodeValue {
+                        ply_remaining: ply_remaining.saturating_add(6),
+                        node_type,
+                        value,
+                        transposition_move: EncodedMove::NONE,
+                    },
+                    ply_from_root,
+                );
+                return value;
             }
-        } else {
-            None
-        };
+        }
+
         if ply_from_root == 0 {
             // Use iterative deepening move as hash move
             hash_move = self.pv.root_best_move();
@@ -738,9 +1196,9 @@ impl Search {
             ply_remaining = ply_remaining.saturating_sub(param!(self).iir_depth_reduction);
         }
 
-        if ply_remaining == 0 {
-            // Enter quiescence search
-            return self.quiescence_search(alpha, beta);
+        if ply_remaining == 0 || ply_from_root >= MAX_PLY {
+            // Enter quiescence search, which stops at the deepest ply
+            return self.quiescence_search(ply_from_root, true, alpha, beta);
         }
 
         let move_generator = MoveGenerator::new(&self.board);
@@ -770,18 +1228,29 @@ impl Search {
             self.get_correction(static_eval, pawn_index, minor_piece_index)
         };
 
-        let improving = if move_generator.is_in_check() {
-            if ply_from_root >= 2 {
-                self.eval_history[ply_from_root as usize] =
-                    self.eval_history[ply_from_root as usize - 2];
+        let in_check = move_generator.is_in_check();
+        let two_plies_ago_eval = self
+            .stack
+            .previous(ply_from_root, 2)
+            .map(|frame| frame.static_eval);
+        let improving = if in_check {
+            if let Some(two_plies_ago_eval) = two_plies_ago_eval {
+                self.stack[ply_from_root].static_eval = two_plies_ago_eval;
             }
             false
         } else {
-            self.eval_history[ply_from_root as usize] = static_eval;
-            ply_from_root >= 2 && static_eval > self.eval_history[ply_from_root as usize - 2]
+            self.stack[ply_from_root].static_eval = static_eval;
+            two_plies_ago_eval.is_some_and(|two_plies_ago_eval| static_eval > two_plies_ago_eval)
         };
 
         if is_not_pv_node && !move_generator.is_in_check() {
+            if USE_RAZORING
+                && excluded_move.is_none()
+                && let Some(score) = self.razoring(static_eval, ply_remaining, ply_from_root, alpha)
+            {
+                return score;
+            }
+
             // Static null move pruning (also known as reverse futility pruning)
             if USE_STATIC_NULL_MOVE_PRUNING {
                 let static_null_margin = if improving {
@@ -791,6 +1260,8 @@ impl Search {
                 };
                 if ply_remaining < param!(self).static_null_min_depth
                     && static_eval - i32::from(ply_remaining) * static_null_margin > beta
+                    // Known wins still have to be played out
+                    && static_eval < endgame::KNOWN_WIN
                 {
                     return static_eval;
                 }
@@ -799,6 +1270,7 @@ impl Search {
             // Null move pruning
             if USE_NULL_MOVE_PRUNING
             && allow_null_move
+            && excluded_move.is_none()
             && ply_remaining > param!(self).nmp_min_depth
 
             && static_eval >= beta
@@ -808,6 +1280,9 @@ impl Search {
                 != move_generator.friendly_pieces().count()
             {
                 let old_state = self.make_null_move();
+                let frame = &mut self.stack[ply_from_root];
+                frame.current_move = EncodedMove::NONE;
+                frame.moved_piece = None;
 
                 let score = -self.negamax(
                     time_manager,
@@ -826,56 +1301,108 @@ impl Search {
                     return score;
                 }
             }
+
+            if USE_PROBCUT
+                && excluded_move.is_none()
+                && ply_remaining >= param!(self).probcut_min_depth
+                && !Self::score_is_checkmate(beta)
+                && let Some(score) = self.probcut(
+                    time_manager,
+                    &move_generator,
+                    static_eval,
+                    ply_remaining,
+                    ply_from_root,
+                    beta,
+                )
+            {
+                return score;
+            }
         }
 
-        // Get legal moves and their estimated value
-        let (mut move_guesses, move_count) = MoveOrderer::get_move_guesses(
-            self,
-            &move_generator,
+        // Moves are generated and ordered as they are needed
+        let mut move_picker = MovePicker::new(
             hash_move,
-            if USE_KILLER_MOVE && (ply_from_root as usize) < self.killer_moves.len() {
-                self.killer_moves[ply_from_root as usize]
+            if USE_KILLER_MOVE {
+                self.stack[ply_from_root].killer_move
             } else {
                 EncodedMove::NONE
             },
+            self.previous_move(ply_from_root, 1).map_or(
+                EncodedMove::NONE,
+                |(previous_piece, previous_to)| {
+                    self.counter_moves[previous_piece as usize][previous_to.usize()]
+                },
+            ),
+            excluded_move,
+            ply_from_root,
         );
 
-        if move_count == 0 {
-            // No moves
-            let score = if move_generator.is_in_check() {
-                // Checkmate
-                -IMMEDIATE_CHECKMATE_SCORE + EvalNumber::from(ply_from_root)
-            } else {
-                // Stalemate
-                0
-            };
-            return score;
-        }
-
         let mut node_type = NodeType::Alpha;
         let (mut best_move, mut best_score) = (EncodedMove::NONE, -EvalNumber::MAX);
 
         let mut quiets_evaluated: Vec<EncodedMove> = Vec::new();
         let mut captures_evaluated: Vec<EncodedMove> = Vec::new();
         let mut index = 0;
-        loop {
-            let encoded_move_data = unsafe {
-                // SAFETY: `put_highest_guessed_move` guarantees that `move_guesses[0..move_count]` are initialised.
-                // `index` can not be higher than `move_count`, due to the loop condition.
+        while let Some(encoded_move_data) = move_picker.next(self, &move_generator) {
+            let move_data = encoded_move_data.decode();
 
-                MoveOrderer::put_highest_guessed_move(&mut move_guesses, index, move_count)
+            if ply_from_root == 0
+                && ((!self.root_moves.is_empty() && !self.root_moves.contains(&encoded_move_data))
+                    || self.excluded_root_moves.contains(&encoded_move_data))
+            {
+                index += 1;
+                continue;
             }
-            .move_data;
-            let move_data = encoded_move_data.decode();
 
             // This won't consider en passant
             let is_capture = move_generator.enemy_piece_bit_board().get(&move_data.to);
 
+            if is_capture
+                && is_not_pv_node
+                && index != 0
+                && ply_remaining <= param!(self).see_pruning_max_depth
+                && !move_generator.is_in_check()
+                && !see(
+                    &self.board,
+                    move_data,
+                    -param!(self).see_capture_margin * i32::from(ply_remaining),
+                )
+            {
+                // Static exchange evaluation pruning
+                index += 1;
+                continue;
+            }
+
+            // Extending is stopped far beyond the root depth, so extensions can not stack without limit
+            let singularity = if USE_SINGULAR_EXTENSIONS
+                && ply_from_root != 0
+                && u16::from(ply_from_root) < 2 * u16::from(self.root_depth)
+                && encoded_move_data == hash_move
+                && excluded_move.is_none()
+                && let Some(saved) = saved
+            {
+                self.singularity(time_manager, saved, ply_remaining, ply_from_root, beta)
+            } else {
+                Singularity::Unknown
+            };
+            if let Singularity::MultiCut(score) = singularity {
+                return score;
+            }
+
+            let moving_piece = self.board.friendly_piece_at(move_data.from).unwrap();
+            let frame = &mut self.stack[ply_from_root];
+            frame.current_move = encoded_move_data;
+            frame.moved_piece = Some((moving_piece, move_data.to));
+
+            let nodes_before_move = self.node_count();
             let old_state = self.make_move_repetition::<true>(&move_data);
-            self.node_count += 1;
+            self.increment_node_count();
 
-            // Search deeper when in check
+            // Search deeper when in check, or when only the hash move is good
             let check_extension = MoveGenerator::calculate_is_in_check(&self.board);
+            let extension = check_extension || matches!(singularity, Singularity::Singular);
+            let new_depth = (ply_remaining + Ply::from(extension))
+                .saturating_sub(1 + Ply::from(matches!(singularity, Singularity::NotSingular)));
 
             let mut normal_search = check_extension // Do not reduce if extending
                 || is_capture // Do not reduce if it's a capture
@@ -890,7 +1417,11 @@ impl Search {
                     let mut r = param!(self).lmr_base;
                     r += u32::from(ply_remaining) * param!(self).lmr_ply_multiplier;
                     r += (index as u32) * param!(self).lmr_index_multiplier;
-                    (r / 1024) as u8
+
+                    // Reduce quiet moves with good history less
+                    let history = self.quiet_history_score(ply_from_root, moving_piece, move_data)
+                        / param!(self).lmr_history_divisor;
+                    (r.saturating_add_signed(-history) / 1024) as u8
                 };
                 score = -self.negamax(
                     time_manager,
@@ -909,7 +1440,7 @@ impl Search {
             if USE_PVS && normal_search && index != 0 {
                 score = -self.negamax(
                     time_manager,
-                    ply_remaining - 1 + Ply::from(check_extension),
+                    new_depth,
                     ply_from_root + 1,
                     true,
                     -alpha - 1,
@@ -920,7 +1451,7 @@ impl Search {
             if normal_search {
                 score = -self.negamax(
                     time_manager,
-                    ply_remaining - 1 + Ply::from(check_extension),
+                    new_depth,
                     ply_from_root + 1,
                     true,
                     -beta,
@@ -929,8 +1460,12 @@ impl Search {
             }
 
             self.unmake_move_repetition(&move_data, &old_state);
+            if ply_from_root == 0 {
+                self.root_move_nodes[encoded_move_data.without_flag() as usize] +=
+                    self.node_count() - nodes_before_move;
+            }
 
-            if ply_remaining > 1 && time_manager.hard_stop_inner_search(self.node_count) {
+            if ply_remaining > 1 && time_manager.hard_stop_inner_search(self.node_count()) {
                 return 0;
             }
 
@@ -963,13 +1498,6 @@ impl Search {
                             }]
                         }
 
-                        const MAX_HISTORY: i32 = 16384;
-                        fn history_gravity(current_value: i16, history_bonus: i32) -> i16 {
-                            (history_bonus
-                                - (i32::from(current_value) * history_bonus.abs() / MAX_HISTORY))
-                                as i16
-                        }
-
                         if is_capture {
                             let history_bonus = (param!(self).capture_history_multiplier_bonus
                                 * i32::from(ply_remaining)
@@ -980,21 +1508,23 @@ impl Search {
                         } else {
                             // Not a capture but still caused beta cutoff, sort this higher later
 
-                            if (ply_from_root as usize) < self.killer_moves.len() {
-                                self.killer_moves[usize::from(ply_from_root)] = encoded_move_data;
+                            self.stack[ply_from_root].killer_move = encoded_move_data;
+                            if let Some((previous_piece, previous_to)) =
+                                self.previous_move(ply_from_root, 1)
+                            {
+                                self.counter_moves[previous_piece as usize][previous_to.usize()] =
+                                    encoded_move_data;
                             }
 
                             let history_bonus = (param!(self).quiet_history_multiplier_bonus
                                 * i32::from(ply_remaining)
                                 - param!(self).quiet_history_subtraction_bonus)
                                 .min(MAX_HISTORY);
-
-                            let history_side =
-                                &mut self.quiet_history[usize::from(self.board.white_to_move)];
-
-                            let history =
-                                &mut history_side[encoded_move_data.without_flag() as usize];
-                            *history += history_gravity(*history, history_bonus);
+                            self.update_quiet_history(
+                                ply_from_root,
+                                encoded_move_data,
+                                history_bonus,
+                            );
 
                             let quiet_history_malus = -(param!(self)
                                 .quiet_history_multiplier_malus
@@ -1002,9 +1532,11 @@ impl Search {
                                 - param!(self).quiet_history_subtraction_malus)
                                 .min(MAX_HISTORY);
                             for previous_quiet in quiets_evaluated {
-                                let history =
-                                    &mut history_side[previous_quiet.without_flag() as usize];
-                                *history += history_gravity(*history, quiet_history_malus);
+                                self.update_quiet_history(
+                                    ply_from_root,
+                                    previous_quiet,
+                                    quiet_history_malus,
+                                );
                             }
                         }
 
@@ -1031,7 +1563,11 @@ impl Search {
             if is_capture {
                 captures_evaluated.push(encoded_move_data);
             } else {
-                if is_not_pv_node && !move_generator.is_in_check() {
+                // Known wins are left to the search, which needs every quiet move to find the mate
+                if is_not_pv_node
+                    && !move_generator.is_in_check()
+                    && static_eval.abs() < endgame::KNOWN_WIN
+                {
                     if USE_FUTILITY_PRUNING
                         && static_eval + param!(self).futility_margin * i32::from(ply_remaining)
                             < alpha
@@ -1052,9 +1588,28 @@ impl Search {
             }
 
             index += 1;
-            if index == move_count {
-                break;
+        }
+
+        // Pruning only stops the loop after a move has been searched
+        if index == 0 && best_move.is_none() {
+            if !excluded_move.is_none() {
+                // The excluded move is the only legal move
+                return alpha;
             }
+
+            // No moves
+            let score = if move_generator.is_in_check() {
+                // Checkmate
+                -IMMEDIATE_CHECKMATE_SCORE + EvalNumber::from(ply_from_root)
+            } else {
+                // Stalemate
+                0
+            };
+            return score;
+        }
+
+        if excluding_moves {
+            return best_score;
         }
 
         if !move_generator.is_in_check() && !Self::score_is_checkmate(best_score) {
@@ -1104,17 +1659,214 @@ impl Search {
         }
 
         // Save to transposition table
-        self.transposition_table[zobrist_index] = Some(NodeValue {
-            zobrist_key_32: zobrist_key.lower_u32(),
-            ply_remaining,
-            node_type,
-            value: best_score,
-            transposition_move: best_move,
-        });
+        self.transposition_table.store(
+            zobrist_key,
+            NodeValue {
+                ply_remaining,
+                node_type,
+                value: best_score,
+                transposition_move: best_move,
+            },
+            ply_from_root,
+        );
 
         best_score
     }
 
+    /// Whether `move_data` is legal in the current position.
+    /// Moves remembered from other positions, like the hash move or killer moves, might not be.
+    pub(super) fn is_valid_move(move_generator: &MoveGenerator, move_data: EncodedMove) -> bool {
+        !move_data.is_none() && move_generator.is_legal(move_data.decode())
+    }
+
+    /// Returns the piece moved `plies_ago` plies before `ply_from_root` and where it moved to,
+    /// or `None` if it was a null move or before the root.
+    fn previous_move(&self, ply_from_root: Ply, plies_ago: Ply) -> Option<(Piece, Square)> {
+        self.stack
+            .previous(ply_from_root, plies_ago)
+            .and_then(|frame| frame.moved_piece)
+    }
+
+    /// Returns the butterfly and continuation history of a quiet move.
+    pub(super) fn quiet_history_score(
+        &self,
+        ply_from_root: Ply,
+        moving_piece: Piece,
+        move_data: Move,
+    ) -> i32 {
+        let mut score = i32::from(
+            self.quiet_history[usize::from(self.board.white_to_move)]
+                [EncodedMove::new(move_data).without_flag() as usize],
+        );
+        for plies_ago in [1, 2] {
+            if let Some((previous_piece, previous_to)) =
+                self.previous_move(ply_from_root, plies_ago)
+            {
+                score += i32::from(
+                    self.continuation_history[previous_piece as usize][previous_to.usize()]
+                        [moving_piece as usize][move_data.to.usize()],
+                );
+            }
+        }
+        score
+    }
+
+    /// Moves the butterfly and continuation history of a quiet move towards `bonus`.
+    fn update_quiet_history(&mut self, ply_from_root: Ply, move_data: EncodedMove, bonus: i32) {
+        let history = &mut self.quiet_history[usize::from(self.board.white_to_move)]
+            [move_data.without_flag() as usize];
+        *history += history_gravity(*history, bonus);
+
+        let moving_piece = self.board.friendly_piece_at(move_data.from()).unwrap();
+        for plies_ago in [1, 2] {
+            if let Some((previous_piece, previous_to)) =
+                self.previous_move(ply_from_root, plies_ago)
+            {
+                let history = &mut self.continuation_history[previous_piece as usize]
+                    [previous_to.usize()][moving_piece as usize][move_data.to().usize()];
+                *history += history_gravity(*history, bonus);
+            }
+        }
+    }
+
+    /// Searches only captures when the position is too far below `alpha` for a quiet move to help.
+    /// Returns the score if the captures do not raise `alpha` either.
+    fn razoring(
+        &mut self,
+        static_eval: EvalNumber,
+        ply_remaining: Ply,
+        ply_from_root: Ply,
+        alpha: EvalNumber,
+    ) -> Option<EvalNumber> {
+        if ply_remaining > param!(self).razoring_max_depth
+            || static_eval + param!(self).razoring_margin * i32::from(ply_remaining) >= alpha
+        {
+            return None;
+        }
+
+        let score = self.quiescence_search(ply_from_root, true, alpha, alpha + 1);
+        (score <= alpha).then_some(score)
+    }
+
+    /// Searches captures that win enough material to beat `beta` by a margin.
+    /// If one still does at a reduced depth, a full search would very likely fail high too.
+    fn probcut(
+        &mut self,
+        time_manager: &TimeManager,
+        move_generator: &MoveGenerator,
+        static_eval: EvalNumber,
+        ply_remaining: Ply,
+        ply_from_root: Ply,
+        beta: EvalNumber,
+    ) -> Option<EvalNumber> {
+        let probcut_beta = beta + param!(self).probcut_margin;
+        let depth = ply_remaining.saturating_sub(param!(self).probcut_depth_reduction);
+
+        let (mut move_guesses, move_count) =
+            MoveOrderer::get_move_guesses_captures_only(self, move_generator);
+        for index in 0..move_count {
+            let move_data = unsafe {
+                // SAFETY: `get_move_guesses_captures_only` guarantees that `move_guesses[0..move_count]` are initialised.
+                // `index` is always lower than `move_count`, due to the range.
+
+                MoveOrderer::put_highest_guessed_move(&mut move_guesses, index, move_count)
+            }
+            .move_data;
+            if !see(&self.board, move_data.decode(), probcut_beta - static_eval) {
+                continue;
+            }
+
+            // Check cheaply first
+            let mut score = self.quiescence_search_move(
+                ply_from_root,
+                move_data,
+                probcut_beta - 1,
+                probcut_beta,
+            );
+            if score >= probcut_beta {
+                let decoded = move_data.decode();
+                self.stack[ply_from_root].current_move = move_data;
+                self.stack[ply_from_root].moved_piece = self
+                    .board
+                    .friendly_piece_at(decoded.from)
+                    .map(|moving_piece| (moving_piece, decoded.to));
+
+                let old_state = self.make_move_repetition::<true>(&decoded);
+                self.increment_node_count();
+                score = -self.negamax(
+                    time_manager,
+                    depth,
+                    ply_from_root + 1,
+                    true,
+                    -probcut_beta,
+                    -probcut_beta + 1,
+                );
+                self.unmake_move_repetition(&decoded, &old_state);
+            }
+
+            if time_manager.hard_stop_inner_search(self.node_count()) {
+                return Some(0);
+            }
+            if score >= probcut_beta {
+                self.transposition_table.store(
+                    self.position_zobrist_key(),
+                    NodeValue {
+                        ply_remaining: depth + 1,
+                        node_type: NodeType::Beta,
+                        value: score,
+                        transposition_move: move_data,
+                    },
+                    ply_from_root,
+                );
+                return Some(score);
+            }
+        }
+
+        None
+    }
+
+    /// Searches every move except the hash move with a reduced depth and a lowered window,
+    /// to find out whether the hash move is much better than the rest.
+    fn singularity(
+        &mut self,
+        time_manager: &TimeManager,
+        saved: NodeValue,
+        ply_remaining: Ply,
+        ply_from_root: Ply,
+        beta: EvalNumber,
+    ) -> Singularity {
+        if ply_remaining < param!(self).singular_min_depth
+            || matches!(saved.node_type, NodeType::Alpha)
+            || saved.ply_remaining + 3 < ply_remaining
+            || Self::score_is_checkmate(saved.value)
+        {
+            return Singularity::Unknown;
+        }
+
+        let singular_beta = saved.value - param!(self).singular_margin * i32::from(ply_remaining);
+        self.stack[ply_from_root].excluded_move = saved.transposition_move;
+        let score = self.negamax(
+            time_manager,
+            (ply_remaining - 1) / 2,
+            ply_from_root,
+            false,
+            singular_beta - 1,
+            singular_beta,
+        );
+        self.stack[ply_from_root].excluded_move = EncodedMove::NONE;
+
+        if score < singular_beta {
+            Singularity::Singular
+        } else if singular_beta >= beta {
+            // Another move also beats beta, so this node will most likely fail high
+            Singularity::MultiCut(singular_beta)
+        } else if saved.value >= beta {
+            Singularity::NotSingular
+        } else {
+            Singularity::Unknown
+        }
+    }
+
     /// Returns whether a score means forced checkmate.
     #[must_use]
     pub const fn score_is_checkmate(score: EvalNumber) -> bool {
@@ -1128,6 +1880,7 @@ impl Search {
         mut best_score: EvalNumber,
         depth: Ply,
     ) -> EvalNumber {
+        self.root_depth = depth;
         if USE_ASPIRATION_WINDOWS && depth > 2 {
             let mut alpha = best_score
                 .saturating_sub(param!(self).aspiration_window_start)
@@ -1169,21 +1922,82 @@ impl Search {
 
         depth_completed: &mut dyn FnMut(DepthSearchInfo),
     ) -> (Ply, EvalNumber) {
+        let (depth, lines) = self.iterative_deepening_multi_pv(time_manager, 1, depth_completed);
+        (
+            depth,
+            lines.first().map_or(-EvalNumber::MAX, |&(_, score)| score),
+        )
+    }
+
+    /// Like [`Self::iterative_deepening`], but finds the best `multi_pv` lines, each starting with
+    /// a different move. Every line of a completed depth is reported, best first.
+    ///
+    /// Returns the depth reached and the lines of the last completed depth, best first.
+    #[must_use]
+    pub fn iterative_deepening_multi_pv(
+        &mut self,
+
+        time_manager: &TimeManager,
+        multi_pv: usize,
+
+        depth_completed: &mut dyn FnMut(DepthSearchInfo),
+    ) -> (Ply, Vec<(Pv, EvalNumber)>) {
         let mut depth = 0;
-        let mut previous_best_score = -EvalNumber::MAX;
+        let mut lines: Vec<(Pv, EvalNumber)> = Vec::new();
 
         let mut best_move_stability = 0;
         let mut previous_best_move = EncodedMove::NONE;
+        let mut previous_best_scores: Vec<EvalNumber> = Vec::new();
+
+        self.root_moves = self.tablebase_root_moves();
+        if self.root_moves.is_empty() {
+            self.root_moves.clone_from(&self.requested_root_moves);
+        }
+
+        // Even without moves, one search is needed to find checkmate or stalemate
+        let line_count = multi_pv.min(self.root_move_count()).max(1);
 
-        loop {
+        'deepening: loop {
             depth += 1;
-            let best_score = self.aspiration_search(time_manager, previous_best_score, depth);
+            if self.thread_index != 0
+                && depth != Ply::MAX
+                && Self::helper_skips_depth(self.thread_index, depth)
+            {
+                continue;
+            }
 
-            if time_manager.hard_stop_iterative_deepening(depth, self.node_count) {
-                // Must stop now.
-                break;
+            let previous_lines = core::mem::take(&mut lines);
+            self.excluded_root_moves.clear();
+            for line in 0..line_count {
+                // Start from this line at the previous depth
+                let previous_score = if let Some((pv, score)) = previous_lines.get(line) {
+                    self.pv.clone_from(pv);
+                    *score
+                } else {
+                    -EvalNumber::MAX
+                };
+
+                let score = self.aspiration_search(time_manager, previous_score, depth);
+
+                if time_manager.hard_stop_iterative_deepening(depth, self.node_count()) {
+                    // Must stop now.
+                    if lines.is_empty() {
+                        lines = previous_lines;
+                    } else {
+                        // The best line of this depth was completed
+                        self.pv.clone_from(&lines[0].0);
+                    }
+                    break 'deepening;
+                }
+
+                lines.push((self.pv.clone(), score));
+                self.excluded_root_moves.push(self.pv.root_best_move());
             }
-            previous_best_score = best_score;
+            self.excluded_root_moves.clear();
+
+            lines.sort_by_key(|&(_, score)| core::cmp::Reverse(score));
+            let best_score = lines[0].1;
+            self.pv.clone_from(&lines[0].0);
 
             if self.pv.root_best_move().is_none() || Self::score_is_checkmate(best_score) {
                 while time_manager.is_pondering() {}
@@ -1201,12 +2015,16 @@ impl Search {
 
             // Depth was completed
             // Report results of search iteration
-            depth_completed(DepthSearchInfo {
-                depth,
-                best: (self.pv, best_score),
-                highest_depth: self.highest_depth,
-                node_count: self.node_count,
-            });
+            for (index, (pv, score)) in lines.iter().enumerate() {
+                depth_completed(DepthSearchInfo {
+                    depth,
+                    best: (pv.clone(), *score),
+                    highest_depth: self.highest_depth,
+                    node_count: self.node_count(),
+                    tablebase_hits: self.tablebase_hits(),
+                    multi_pv: index + 1,
+                });
+            }
 
             if depth == Ply::MAX {
                 while time_manager.is_pondering() {}
@@ -1214,27 +2032,180 @@ impl Search {
                 break;
             }
 
-            if time_manager.soft_stop(self.node_count, best_score, best_move_stability) {
+            // How much the score fell over the last few iterations
+            const SCORE_TREND_ITERATIONS: usize = 3;
+            let score_drop = previous_best_scores
+                .len()
+                .checked_sub(SCORE_TREND_ITERATIONS)
+                .map_or(0, |index| {
+                    previous_best_scores[index].saturating_sub(best_score)
+                });
+            previous_best_scores.push(best_score);
+
+            let best_move_nodes =
+                self.root_move_nodes[self.pv.root_best_move().without_flag() as usize];
+            if time_manager.soft_stop(
+                self.node_count(),
+                best_score,
+                best_move_stability,
+                best_move_nodes,
+                score_drop,
+            ) {
                 // It would probably be a waste of time to start another iteration
                 break;
             }
         }
+        self.excluded_root_moves.clear();
+
+        (depth, lines)
+    }
+
+    /// Returns how many moves the root can choose between.
+    fn root_move_count(&self) -> usize {
+        let mut count = 0;
+        MoveGenerator::new(&self.board).generate(
+            |move_data| {
+                if self.root_moves.is_empty()
+                    || self.root_moves.contains(&EncodedMove::new(move_data))
+                {
+                    count += 1;
+                }
+            },
+            false,
+        );
+        count
+    }
+
+    /// Returns whether a helper search should skip a depth of iterative deepening,
+    /// so that the threads of lazy SMP spread out over different depths.
+    const fn helper_skips_depth(thread_index: usize, depth: Ply) -> bool {
+        const SKIP_SIZE: [u16; 20] = [1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4];
+        const SKIP_PHASE: [u16; 20] = [0, 1, 0, 1, 2, 3, 0, 1, 2, 3, 4, 5, 0, 1, 2, 3, 4, 5, 6, 7];
 
-        (depth, previous_best_score)
+        let index = (thread_index - 1) % SKIP_SIZE.len();
+        ((depth as u16 + SKIP_PHASE[index]) / SKIP_SIZE[index]) % 2 == 1
+    }
+
+    fn increment_node_count(&self) {
+        // Only this thread writes to the counter, so it does not need to be an atomic add
+        self.node_count
+            .store(self.node_count() + 1, Ordering::Relaxed);
     }
 
     /// Returns how many times `make_move` was called in search
     #[must_use]
-    pub const fn node_count(&self) -> u64 {
-        self.node_count
+    pub fn node_count(&self) -> u64 {
+        self.node_count.load(Ordering::Relaxed)
     }
 
+    /// Returns a handle to the node count, which other threads can read while this searches.
     #[must_use]
-    pub fn calculate_time(clock_time: u64, increment: u64) -> (u64, u64) {
-        let max_time = clock_time / 2;
-        let hard_time_limit = (clock_time / 6 + increment * 2).min(max_time);
-        let soft_time_limit = (clock_time / 24 + increment / 2).min(hard_time_limit);
-        (hard_time_limit, soft_time_limit)
+    pub fn node_counter(&self) -> Arc<AtomicU64> {
+        self.node_count.clone()
+    }
+
+    /// Returns how many times the pawn table was probed this search, and how many of those were hits.
+    #[must_use]
+    pub const fn pawn_table_statistics(&self) -> (u64, u64) {
+        self.pawn_table.statistics()
+    }
+
+    /// Returns how many positions were found in the tablebase.
+    #[must_use]
+    pub fn tablebase_hits(&self) -> u64 {
+        self.tablebase_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns a handle to the tablebase hits, which other threads can read while this searches.
+    #[must_use]
+    pub fn tablebase_hit_counter(&self) -> Arc<AtomicU64> {
+        self.tablebase_hits.clone()
+    }
+
+    fn increment_tablebase_hits(&self) {
+        self.tablebase_hits
+            .store(self.tablebase_hits() + 1, Ordering::Relaxed);
+    }
+
+    /// Returns how many pieces the tablebase can be probed with.
+    fn tablebase_piece_limit(&self) -> usize {
+        self.tablebase.as_ref().map_or(0, |tablebase| {
+            tablebase.max_pieces().min(self.tablebase_probe_limit)
+        })
+    }
+
+    fn piece_count(&self) -> usize {
+        self.board
+            .bit_boards
+            .iter()
+            .map(|bit_board| bit_board.count() as usize)
+            .sum()
+    }
+
+    /// Returns the win/draw/loss of the position if it has few enough pieces to be probed.
+    fn probe_tablebase_wdl(&mut self) -> Option<Wdl> {
+        if self.piece_count() > self.tablebase_piece_limit() {
+            return None;
+        }
+        let wdl = self.tablebase.as_ref()?.probe_wdl(&mut self.board)?;
+        self.increment_tablebase_hits();
+        Some(wdl)
+    }
+
+    /// Returns the allowed root moves that keep the best outcome according to the tablebase, or
+    /// nothing if the root can not be probed.
+    fn tablebase_root_moves(&mut self) -> Vec<EncodedMove> {
+        if self.piece_count() > self.tablebase_piece_limit() {
+            return Vec::new();
+        }
+        let Some(ranked) = self
+            .tablebase
+            .as_ref()
+            .and_then(|tablebase| tablebase.rank_root_moves(&mut self.board))
+        else {
+            return Vec::new();
+        };
+        self.increment_tablebase_hits();
+
+        let ranked: Vec<(EncodedMove, i32)> = ranked
+            .into_iter()
+            .map(|(move_data, rank)| (EncodedMove::new(move_data), rank))
+            .filter(|(move_data, _)| {
+                self.requested_root_moves.is_empty()
+                    || self.requested_root_moves.contains(move_data)
+            })
+            .collect();
+        let best_rank = ranked.iter().map(|&(_, rank)| rank).max().unwrap_or(0);
+        ranked
+            .into_iter()
+            .filter(|&(_, rank)| rank == best_rank)
+            .map(|(move_data, _)| move_data)
+            .collect()
+    }
+
+    /// Returns the hard and soft time limits for a move.
+    ///
+    /// `move_overhead` is kept in reserve for lag. With `moves_to_go`, the clock is spread over the moves
+    /// until the next time control, otherwise it is assumed to last for about 24 more moves.
+    #[must_use]
+    pub fn calculate_time(
+        clock_time: u64,
+        increment: u64,
+        moves_to_go: Option<NonZeroU16>,
+        move_overhead: u64,
+    ) -> (u64, u64) {
+        const SUDDEN_DEATH_MOVES: u64 = 24;
+
+        let available_time = clock_time.saturating_sub(move_overhead).max(1);
+        let moves_left = moves_to_go.map_or(SUDDEN_DEATH_MOVES, |moves_to_go| {
+            // One move is kept in reserve so that the clock is not emptied before the time control
+            u64::from(moves_to_go.get()) + 1
+        });
+
+        let max_time = (available_time / 2).max(1);
+        let soft_time_limit = available_time / moves_left + increment / 2;
+        let hard_time_limit = (soft_time_limit * 4).min(max_time);
+        (hard_time_limit, soft_time_limit.min(hard_time_limit))
     }
 
     #[must_use]
@@ -1290,11 +2261,45 @@ impl Search {
 
 #[cfg(test)]
 mod tests {
+    use core::num::NonZeroU16;
+
     use crate::{
-        board::Board,
+        board::{Board, square::Square},
         evaluation::{Eval, eval_data::EvalNumber},
-        search::{Search, search_params::DEFAULT_TUNABLES, transposition::megabytes_to_capacity},
+        move_generator::{
+            MoveGenerator,
+            move_data::{Flag, Move},
+        },
+        search::{
+            IMMEDIATE_CHECKMATE_SCORE, Ply, Search, search_params::DEFAULT_TUNABLES,
+            time_manager::TimeManager, transposition::megabytes_to_capacity,
+        },
     };
+    use std::sync::{Arc, atomic::AtomicBool};
+
+    fn new_search(fen: &str) -> Search {
+        Search::new(
+            Board::from_fen(fen).unwrap(),
+            megabytes_to_capacity(8),
+            #[cfg(feature = "spsa")]
+            DEFAULT_TUNABLES,
+        )
+    }
+
+    fn depth_limit(depth: Ply) -> TimeManager<'static> {
+        TimeManager::depth_limited(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            None,
+            depth,
+        )
+    }
+
+    fn search_to_depth(fen: &str, depth: Ply) -> EvalNumber {
+        new_search(fen)
+            .iterative_deepening(&depth_limit(depth), &mut |_| {})
+            .1
+    }
 
     #[test]
     fn quiescence_search_works() {
@@ -1311,8 +2316,228 @@ mod tests {
                 #[cfg(feature = "spsa")]
                 DEFAULT_TUNABLES,
             )
-            .quiescence_search(-EvalNumber::MAX, EvalNumber::MAX),
+            .quiescence_search(0, false, -EvalNumber::MAX, EvalNumber::MAX),
             Eval::evaluate(&quiet)
         );
     }
+
+    #[test]
+    fn quiescence_search_finds_checkmate() {
+        let mut search = Search::new(
+            Board::from_fen("r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4")
+                .unwrap(),
+            megabytes_to_capacity(8),
+            #[cfg(feature = "spsa")]
+            DEFAULT_TUNABLES,
+        );
+        // Capturing on f7 is checkmate, which standing pat after the capture would miss
+        assert_eq!(
+            search.quiescence_search(0, false, -EvalNumber::MAX, EvalNumber::MAX),
+            IMMEDIATE_CHECKMATE_SCORE - 1
+        );
+
+        // Already checkmated
+        search.new_board(
+            Board::from_fen("r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4")
+                .unwrap(),
+        );
+        assert_eq!(
+            search.quiescence_search(0, false, -EvalNumber::MAX, EvalNumber::MAX),
+            -IMMEDIATE_CHECKMATE_SCORE
+        );
+    }
+
+    #[test]
+    fn multi_pv_lines_start_with_different_moves() {
+        let time_manager = depth_limit(5);
+        let mut search = new_search(Board::START_POSITION_FEN);
+        let (_, lines) = search.iterative_deepening_multi_pv(&time_manager, 4, &mut |_| {});
+
+        assert_eq!(lines.len(), 4);
+        for (index, (pv, score)) in lines.iter().enumerate() {
+            for (other_pv, other_score) in &lines[index + 1..] {
+                assert!(pv.root_best_move() != other_pv.root_best_move());
+                assert!(score >= other_score);
+            }
+        }
+
+        // Only one legal move
+        let mut search = new_search("7k/8/8/8/8/8/6q1/7K w - - 0 1");
+        let (_, lines) = search.iterative_deepening_multi_pv(&time_manager, 4, &mut |_| {});
+        assert_eq!(lines.len(), 1);
+    }
+
+    #[test]
+    fn search_moves_limit_root() {
+        let mut search = new_search("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1");
+        let time_manager = depth_limit(4);
+        let capture = Move {
+            from: Square::from_notation("d1").unwrap(),
+            to: Square::from_notation("d5").unwrap(),
+            flag: Flag::None,
+        };
+        let (_, score) = search.iterative_deepening(&time_manager, &mut |_| {});
+        assert!(search.pv.root_best_move().decode() == capture);
+        assert!(score > 0);
+
+        // The transposition table now has the capture, but it must not be played
+        let king_move = Move {
+            from: Square::from_notation("e1").unwrap(),
+            to: Square::from_notation("e2").unwrap(),
+            flag: Flag::None,
+        };
+        search.clear_for_new_search();
+        search.set_search_moves(&[king_move]);
+        let (_, score) = search.iterative_deepening(&time_manager, &mut |_| {});
+        assert!(search.pv.root_best_move().decode() == king_move);
+        assert!(score < 0);
+    }
+
+    #[test]
+    fn fifty_move_rule_works() {
+        // Every move reaches the hundredth ply, so a queen up is only a draw
+        assert_eq!(search_to_depth("7k/8/8/8/8/8/8/KQ6 w - - 99 1", 4), 0);
+
+        // Unless it is checkmate
+        assert_eq!(
+            search_to_depth("7k/8/6K1/8/8/8/Q7/8 w - - 99 1", 4),
+            IMMEDIATE_CHECKMATE_SCORE - 1
+        );
+    }
+
+    #[test]
+    fn mate_scores_count_plies_from_the_root() {
+        // Ra7 then Rb8 is mate in 2, which is found again through the transposition table at every depth
+        for depth in 4..=7 {
+            assert_eq!(
+                search_to_depth("7k/8/8/8/8/8/R7/1R4K1 w - - 0 1", depth),
+                IMMEDIATE_CHECKMATE_SCORE - 3
+            );
+        }
+        assert_eq!(
+            search_to_depth("7k/8/6K1/8/8/8/8/1R6 b - - 0 1", 6),
+            -IMMEDIATE_CHECKMATE_SCORE + 2
+        );
+    }
+
+    #[test]
+    fn helpers_spread_over_depths() {
+        for depth in 1..=20 {
+            // The first two helpers take turns
+            assert_ne!(
+                Search::helper_skips_depth(1, depth),
+                Search::helper_skips_depth(2, depth)
+            );
+
+            // Helpers past the table start over with it
+            assert_eq!(
+                Search::helper_skips_depth(1, depth),
+                Search::helper_skips_depth(21, depth)
+            );
+        }
+
+        // Every helper keeps searching deeper
+        for thread_index in 1..=20 {
+            let searched = (1..=8)
+                .filter(|&depth| !Search::helper_skips_depth(thread_index, depth))
+                .count();
+            assert!((1..8).contains(&searched));
+        }
+    }
+
+    #[test]
+    fn static_evaluation_uses_the_pawn_table() {
+        let mut search =
+            new_search("r1bqkb1r/pp2pppp/2np1n2/8/3NP3/2N5/PPP2PPP/R1BQKB1R w KQkq - 2 6");
+        search.iterative_deepening(&depth_limit(5), &mut |_| {});
+
+        // Pawn moves are rare in the tree, so most positions share their pawns with another
+        let (probes, hits) = search.pawn_table_statistics();
+        assert!(probes > 0);
+        assert!(hits > probes / 2);
+
+        search.clear_for_new_search();
+        assert_eq!(search.pawn_table_statistics(), (0, 0));
+    }
+
+    #[test]
+    fn time_is_budgeted_until_next_control() {
+        // Sudden death without increment never uses more than half the clock
+        let (hard_time_limit, soft_time_limit) = Search::calculate_time(60_000, 0, None, 0);
+        assert_eq!(soft_time_limit, 2_500);
+        assert_eq!(hard_time_limit, 10_000);
+
+        // The clock is spread over the moves until the next time control
+        let (hard_time_limit, soft_time_limit) =
+            Search::calculate_time(120_000, 0, NonZeroU16::new(39), 0);
+        assert_eq!(soft_time_limit, 3_000);
+        assert_eq!(hard_time_limit, 12_000);
+
+        // The last move before the time control still keeps time in reserve
+        let (hard_time_limit, soft_time_limit) =
+            Search::calculate_time(10_000, 0, NonZeroU16::new(1), 100);
+        assert!(soft_time_limit <= hard_time_limit);
+        assert!(hard_time_limit <= 9_900 / 2);
+
+        // Move overhead is taken away before the clock runs out
+        let (hard_time_limit, _) = Search::calculate_time(50, 0, None, 100);
+        assert!(hard_time_limit <= 1);
+    }
+
+    #[test]
+    fn razoring_agrees_with_a_full_search() {
+        // Black is a queen and rook up, so no white move gets back to equality
+        let mut search = new_search("r2qk3/8/8/8/8/8/PPP5/4K3 w - - 0 1");
+        let static_eval = search.corrected_static_evaluate();
+        for depth in 1..=DEFAULT_TUNABLES.razoring_max_depth {
+            // The highest alpha that is still razored at this depth
+            let alpha = static_eval + DEFAULT_TUNABLES.razoring_margin * i32::from(depth) + 1;
+            let razored = search.razoring(static_eval, depth, 0, alpha);
+            assert!(razored.is_some_and(|score| score <= alpha));
+
+            // The root is never razored, so this is the score without it
+            let score = search.negamax(&depth_limit(depth), depth, 0, false, alpha, alpha + 1);
+            assert!(score <= alpha);
+        }
+
+        // Not far enough behind
+        search.new_board(Board::from_fen("r3k3/8/8/8/8/8/PPP5/4K3 w - - 0 1").unwrap());
+        let static_eval = search.corrected_static_evaluate();
+        assert!(search.razoring(static_eval, 1, 0, 0).is_none());
+    }
+
+    #[test]
+    fn probcut_agrees_with_a_full_search() {
+        // Taking the queen wins far more than beta needs
+        let fen = "4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1";
+        let capture = Move {
+            from: Square::from_notation("d1").unwrap(),
+            to: Square::from_notation("d5").unwrap(),
+            flag: Flag::None,
+        };
+        let beta = 200;
+        let depth = DEFAULT_TUNABLES.probcut_min_depth;
+        let mut search = new_search(fen);
+        let static_eval = search.corrected_static_evaluate();
+        let score = search.probcut(
+            &depth_limit(depth),
+            &MoveGenerator::new(search.board()),
+            static_eval,
+            depth,
+            0,
+            beta,
+        );
+        assert!(score.is_some_and(|score| score >= beta));
+        let saved = search
+            .transposition_table
+            .get(search.position_zobrist_key(), 0)
+            .unwrap();
+        assert!(saved.transposition_move.decode() == capture);
+
+        // The root is never cut, so this is the score without it
+        let mut search = new_search(fen);
+        let (_, score) = search.iterative_deepening(&depth_limit(depth), &mut |_| {});
+        assert!(score >= beta);
+        assert!(search.pv.root_best_move().decode() == capture);
+    }
 }
@@ -3,9 +3,10 @@ use core::mem::MaybeUninit;
 use crate::move_generator::{
     MoveGenerator,
     move_data::{Flag, Move},
+    static_exchange::see,
 };
 
-use super::{Search, encoded_move::EncodedMove};
+use super::{Ply, Search, encoded_move::EncodedMove};
 
 pub type MoveGuessNum = i32;
 
@@ -21,10 +22,9 @@ const MAX_CAPTURES: usize = 74;
 const HASH_MOVE_BONUS: MoveGuessNum = MoveGuessNum::MAX;
 const QUEEN_PROMOTION_BONUS: MoveGuessNum = 50_000_000;
 const CAPTURE_BONUS: MoveGuessNum = 50_000_000;
-const KILLER_MOVE_BONUS: MoveGuessNum = 30_000_000;
 const KNIGHT_PROMOTION_BONUS: MoveGuessNum = 20_000_000;
-const ROOK_PROMOTION_BONUS: MoveGuessNum = 0;
-const BISHOP_PROMOTION_BONUS: MoveGuessNum = 0;
+const ROOK_PROMOTION_BONUS: MoveGuessNum = -CAPTURE_BONUS;
+const BISHOP_PROMOTION_BONUS: MoveGuessNum = -CAPTURE_BONUS;
 
 const CAPTURING_SCORE: [i32; 12] = {
     const SCALE: i32 = 500;
@@ -45,16 +45,15 @@ const CAPTURING_SCORE: [i32; 12] = {
 
 pub struct MoveOrderer;
 impl MoveOrderer {
-    fn guess_move_value(search: &Search, move_data: Move) -> MoveGuessNum {
+    fn guess_move_value(search: &Search, move_data: Move, ply_from_root: Ply) -> MoveGuessNum {
         let moving_from = move_data.from;
         let moving_to = move_data.to;
+        let moving_piece = search.board.friendly_piece_at(moving_from).unwrap();
 
         match move_data.flag {
-            Flag::EnPassant | Flag::Castle => {
-                return MoveGuessNum::from(
-                    search.quiet_history[usize::from(search.board.white_to_move)]
-                        [moving_from.usize() + moving_to.usize() * 64],
-                );
+            Flag::EnPassant => return CAPTURE_BONUS + CAPTURING_SCORE[0],
+            Flag::Castle => {
+                return search.quiet_history_score(ply_from_root, moving_piece, move_data);
             }
 
             Flag::BishopPromotion => return BISHOP_PROMOTION_BONUS,
@@ -69,11 +68,14 @@ impl MoveOrderer {
 
         // This won't consider en passant
         if let Some(capturing) = search.board.enemy_piece_at(moving_to) {
-            score += CAPTURE_BONUS;
+            // Losing captures go after quiet moves
+            score += if see(&search.board, move_data, 0) {
+                CAPTURE_BONUS
+            } else {
+                -CAPTURE_BONUS
+            };
             score += MoveGuessNum::from(CAPTURING_SCORE[capturing as usize]);
 
-            let moving_piece = search.board.friendly_piece_at(moving_from).unwrap();
-
             score += i32::from(
                 search.capture_history[moving_piece as usize][moving_to.usize()][if search
                     .board
@@ -85,10 +87,7 @@ impl MoveOrderer {
                 }],
             );
         } else {
-            score += MoveGuessNum::from(
-                search.quiet_history[usize::from(search.board.white_to_move)]
-                    [moving_from.usize() + moving_to.usize() * 64],
-            );
+            score += search.quiet_history_score(ply_from_root, moving_piece, move_data);
         }
         score
     }
@@ -214,7 +213,7 @@ impl MoveOrderer {
         search: &Search,
         move_generator: &MoveGenerator,
         hash_move: EncodedMove,
-        killer_move: EncodedMove,
+        ply_from_root: Ply,
     ) -> ([MaybeUninit<MoveGuess>; MAX_LEGAL_MOVES], usize) {
         let mut move_guesses = [MaybeUninit::uninit(); MAX_LEGAL_MOVES];
 
@@ -222,13 +221,10 @@ impl MoveOrderer {
         move_generator.generate(
             &mut |move_data| {
                 let encoded = EncodedMove::new(move_data);
-
                 let guess = if encoded == hash_move {
                     HASH_MOVE_BONUS
-                } else if encoded == killer_move {
-                    KILLER_MOVE_BONUS
                 } else {
-                    Self::guess_move_value(search, move_data)
+                    Self::guess_move_value(search, move_data, ply_from_root)
                 };
 
                 move_guesses[index].write(MoveGuess {
@@ -244,6 +240,178 @@ impl MoveOrderer {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    HashMove,
+    GenerateCaptures,
+    GoodCaptures,
+    KillerMove,
+    CounterMove,
+    GenerateQuiets,
+    Quiets,
+    BadCaptures,
+    Done,
+}
+
+/// Picks moves one at a time, only generating and scoring them when they are needed.
+///
+/// The order is the hash move, captures that do not lose material, the killer move,
+/// the counter move, quiet moves, then captures that lose material.
+pub struct MovePicker {
+    stage: Stage,
+
+    hash_move: EncodedMove,
+    killer_move: EncodedMove,
+    counter_move: EncodedMove,
+    excluded_move: EncodedMove,
+    ply_from_root: Ply,
+
+    /// Captures are at `move_guesses[..capture_count]`, quiet moves after them.
+    move_guesses: [MaybeUninit<MoveGuess>; MAX_LEGAL_MOVES],
+    capture_count: usize,
+    move_count: usize,
+
+    capture_index: usize,
+    quiet_index: usize,
+}
+
+impl MovePicker {
+    /// Creates a move picker. Use [`EncodedMove::NONE`] for moves that are not known.
+    pub const fn new(
+        hash_move: EncodedMove,
+        killer_move: EncodedMove,
+        counter_move: EncodedMove,
+        excluded_move: EncodedMove,
+        ply_from_root: Ply,
+    ) -> Self {
+        Self {
+            stage: Stage::HashMove,
+            hash_move,
+            killer_move,
+            counter_move,
+            excluded_move,
+            ply_from_root,
+            move_guesses: [MaybeUninit::uninit(); MAX_LEGAL_MOVES],
+            capture_count: 0,
+            move_count: 0,
+            capture_index: 0,
+            quiet_index: 0,
+        }
+    }
+
+    fn add_move(&mut self, search: &Search, move_data: Move) {
+        let encoded = EncodedMove::new(move_data);
+        if encoded == self.hash_move || encoded == self.excluded_move {
+            // Already picked, or should never be picked
+            return;
+        }
+
+        self.move_guesses[self.move_count].write(MoveGuess {
+            move_data: encoded,
+            guess: MoveOrderer::guess_move_value(search, move_data, self.ply_from_root),
+        });
+        self.move_count += 1;
+    }
+
+    /// Whether a killer or counter move can be picked before the quiet moves are generated.
+    fn is_playable_quiet(
+        &self,
+        search: &Search,
+        move_generator: &MoveGenerator,
+        move_data: EncodedMove,
+    ) -> bool {
+        move_data != self.hash_move
+            && move_data != self.excluded_move
+            && *move_data.flag() != Flag::EnPassant
+            && search.board.enemy_piece_at(move_data.to()).is_none()
+            && Search::is_valid_move(move_generator, move_data)
+    }
+
+    fn pick(&mut self, unsorted_index: usize, end: usize) -> MoveGuess {
+        unsafe {
+            // SAFETY: `add_move` initialised `move_guesses[0..move_count]`, and `end` is never above `move_count`.
+
+            MoveOrderer::put_highest_guessed_move(&mut self.move_guesses, unsorted_index, end)
+        }
+    }
+
+    /// Returns the next move to search, or `None` if every legal move has been picked.
+    pub fn next(&mut self, search: &Search, move_generator: &MoveGenerator) -> Option<EncodedMove> {
+        loop {
+            match self.stage {
+                Stage::HashMove => {
+                    self.stage = Stage::GenerateCaptures;
+                    if self.hash_move != self.excluded_move
+                        && Search::is_valid_move(move_generator, self.hash_move)
+                    {
+                        return Some(self.hash_move);
+                    }
+                    // The hash move might be from a different position with the same key
+                    self.hash_move = EncodedMove::NONE;
+                }
+                Stage::GenerateCaptures => {
+                    self.stage = Stage::GoodCaptures;
+                    move_generator.generate(|move_data| self.add_move(search, move_data), true);
+                    self.capture_count = self.move_count;
+                }
+                Stage::GoodCaptures => {
+                    if self.capture_index < self.capture_count {
+                        let move_guess = self.pick(self.capture_index, self.capture_count);
+                        if move_guess.guess >= 0 {
+                            self.capture_index += 1;
+                            return Some(move_guess.move_data);
+                        }
+                    }
+                    // The remaining captures lose material, so they go after quiet moves
+                    self.stage = Stage::KillerMove;
+                }
+                Stage::KillerMove => {
+                    self.stage = Stage::CounterMove;
+                    if self.is_playable_quiet(search, move_generator, self.killer_move) {
+                        return Some(self.killer_move);
+                    }
+                }
+                Stage::CounterMove => {
+                    self.stage = Stage::GenerateQuiets;
+                    if self.counter_move != self.killer_move
+                        && self.is_playable_quiet(search, move_generator, self.counter_move)
+                    {
+                        return Some(self.counter_move);
+                    }
+                }
+                Stage::GenerateQuiets => {
+                    self.stage = Stage::Quiets;
+                    self.quiet_index = self.move_count;
+                    let (killer_move, counter_move) = (self.killer_move, self.counter_move);
+                    move_generator.generate_quiets(|move_data| {
+                        let encoded = EncodedMove::new(move_data);
+                        if encoded != killer_move && encoded != counter_move {
+                            self.add_move(search, move_data);
+                        }
+                    });
+                }
+                Stage::Quiets => {
+                    if self.quiet_index < self.move_count {
+                        let move_guess = self.pick(self.quiet_index, self.move_count);
+                        self.quiet_index += 1;
+                        return Some(move_guess.move_data);
+                    }
+                    self.stage = Stage::BadCaptures;
+                }
+                Stage::BadCaptures => {
+                    if self.capture_index < self.capture_count {
+                        let move_guess = self.pick(self.capture_index, self.capture_count);
+                        self.capture_index += 1;
+                        return Some(move_guess.move_data);
+                    }
+                    self.stage = Stage::Done;
+                }
+                Stage::Done => return None,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -253,8 +421,11 @@ mod tests {
             move_data::{Flag, Move},
         },
         search::{
-            Search, encoded_move::EncodedMove, move_ordering::MoveOrderer,
-            search_params::DEFAULT_TUNABLES, transposition::megabytes_to_capacity,
+            Search,
+            encoded_move::EncodedMove,
+            move_ordering::{MoveOrderer, MovePicker},
+            search_params::DEFAULT_TUNABLES,
+            transposition::megabytes_to_capacity,
         },
     };
 
@@ -272,7 +443,7 @@ mod tests {
             ),
             &move_generator,
             EncodedMove::NONE,
-            EncodedMove::NONE,
+            0,
         );
 
         let mut index = 0;
@@ -295,4 +466,103 @@ mod tests {
         );
         while next_move().1 {}
     }
+
+    fn picked_moves(board: Board, mut move_picker: MovePicker) -> Vec<EncodedMove> {
+        let move_generator = MoveGenerator::new(&board);
+        let search = Search::new(
+            board,
+            megabytes_to_capacity(8),
+            #[cfg(feature = "spsa")]
+            DEFAULT_TUNABLES,
+        );
+
+        let mut moves = Vec::new();
+        while let Some(move_data) = move_picker.next(&search, &move_generator) {
+            moves.push(move_data);
+        }
+        moves
+    }
+
+    #[test]
+    fn counter_move_is_ordered_before_quiet_moves() {
+        let board = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+        let counter_move = EncodedMove::new(Move {
+            from: Square::from_notation("g1").unwrap(),
+            to: Square::from_notation("f3").unwrap(),
+            flag: Flag::None,
+        });
+
+        let moves = picked_moves(
+            board,
+            MovePicker::new(
+                EncodedMove::NONE,
+                EncodedMove::NONE,
+                counter_move,
+                EncodedMove::NONE,
+                1,
+            ),
+        );
+        assert_eq!(moves.len(), 20);
+        assert!(moves[0] == counter_move);
+    }
+
+    #[test]
+    fn excluded_move_is_skipped() {
+        let board = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+        let excluded_move = EncodedMove::new(Move {
+            from: Square::from_notation("e2").unwrap(),
+            to: Square::from_notation("e4").unwrap(),
+            flag: Flag::PawnTwoUp,
+        });
+
+        let moves = picked_moves(
+            board,
+            MovePicker::new(
+                excluded_move,
+                EncodedMove::NONE,
+                EncodedMove::NONE,
+                excluded_move,
+                0,
+            ),
+        );
+        assert_eq!(moves.len(), 19);
+        assert!(!moves.contains(&excluded_move));
+    }
+
+    #[test]
+    fn invalid_hash_move_is_not_picked() {
+        let board = Board::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+            .unwrap();
+        // Black's capture, so it can not be played by white
+        let hash_move = EncodedMove::new(Move {
+            from: Square::from_notation("d5").unwrap(),
+            to: Square::from_notation("e4").unwrap(),
+            flag: Flag::None,
+        });
+        let killer_move = EncodedMove::new(Move {
+            from: Square::from_notation("f1").unwrap(),
+            to: Square::from_notation("b5").unwrap(),
+            flag: Flag::None,
+        });
+
+        let moves = picked_moves(
+            board,
+            MovePicker::new(
+                hash_move,
+                killer_move,
+                EncodedMove::NONE,
+                EncodedMove::NONE,
+                2,
+            ),
+        );
+        assert_eq!(moves.len(), 31);
+        assert!(!moves.contains(&hash_move));
+
+        // exd5 is the only capture
+        assert!(moves[0].to() == Square::from_notation("d5").unwrap());
+        assert!(moves[1] == killer_move);
+        for (index, move_data) in moves.iter().enumerate() {
+            assert!(!moves[index + 1..].contains(move_data));
+        }
+    }
 }
@@ -0,0 +1,98 @@
+//! Cache of the evaluation terms that only depend on the pawns.
+
+use crate::{
+    board::Board,
+    evaluation::{eval_data, terms::PawnStructure},
+};
+
+use super::zobrist::Zobrist;
+
+/// How many pawn structures are kept.
+const PAWN_TABLE_LENGTH: usize = 16384;
+
+#[derive(Clone, Copy)]
+struct Entry {
+    key: Zobrist,
+    pawn_structure: PawnStructure,
+}
+
+/// Pawn structures indexed by the pawn zobrist key, always replacing the previous entry.
+pub struct PawnTable {
+    entries: Box<[Option<Entry>]>,
+
+    /// How many times a pawn structure was looked up.
+    probes: u64,
+
+    /// How many of the lookups found the pawn structure already evaluated.
+    hits: u64,
+}
+
+impl PawnTable {
+    pub fn new() -> Self {
+        Self {
+            entries: vec![None; PAWN_TABLE_LENGTH].into_boxed_slice(),
+            probes: 0,
+            hits: 0,
+        }
+    }
+
+    /// Returns the pawn structure of `board`, evaluating it if it is not in the table.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn probe(&mut self, key: Zobrist, board: &Board) -> &mut PawnStructure {
+        self.probes += 1;
+
+        let entry = &mut self.entries[key.modulo(PAWN_TABLE_LENGTH as u64) as usize];
+        if let Some(entry) = entry
+            && entry.key == key
+        {
+            self.hits += 1;
+        } else {
+            *entry = Some(Entry {
+                key,
+                pawn_structure: PawnStructure::new(
+                    &eval_data::MIDDLE_GAME_TERMS,
+                    &eval_data::END_GAME_TERMS,
+                    board,
+                ),
+            });
+        }
+
+        &mut entry.as_mut().unwrap().pawn_structure
+    }
+
+    /// Returns how many lookups there were, and how many of them were hits.
+    pub const fn statistics(&self) -> (u64, u64) {
+        (self.probes, self.hits)
+    }
+
+    /// Resets the hit statistics, keeping the entries.
+    pub const fn clear_statistics(&mut self) {
+        self.probes = 0;
+        self.hits = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        search::{pawn_table::PawnTable, zobrist::Zobrist},
+    };
+
+    #[test]
+    fn same_pawns_hit() {
+        let mut pawn_table = PawnTable::new();
+        let board = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+        let moved_knight =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1").unwrap();
+
+        let score = pawn_table.probe(Zobrist::pawn_key(&board), &board).score;
+        assert_eq!(
+            pawn_table
+                .probe(Zobrist::pawn_key(&moved_knight), &moved_knight)
+                .score,
+            score
+        );
+        assert_eq!(pawn_table.statistics(), (2, 1));
+    }
+}
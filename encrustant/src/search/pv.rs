@@ -5,17 +5,23 @@ use super::{Ply, encoded_move::EncodedMove};
 pub type PvTable = [[EncodedMove; Ply::MAX as usize]; Ply::MAX as usize];
 pub type PvLength = [Ply; Ply::MAX as usize];
 
-#[derive(Clone, Copy)]
+/// The table is boxed, so copies of lines do not overflow the stack of search threads.
+#[derive(Clone)]
 pub struct Pv {
-    pv_table: PvTable,
+    pv_table: Box<PvTable>,
     pv_length: PvLength,
 }
 
 impl Pv {
     #[must_use]
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
+        let Ok(pv_table) =
+            vec![[EncodedMove::NONE; Ply::MAX as usize]; Ply::MAX as usize].try_into()
+        else {
+            unreachable!("The table has a row for every ply")
+        };
         Self {
-            pv_table: [[EncodedMove::NONE; Ply::MAX as usize]; Ply::MAX as usize],
+            pv_table,
             pv_length: [0; Ply::MAX as usize],
         }
     }
@@ -27,13 +33,13 @@ impl Pv {
 
     /// Returns the best move at the first ply.
     #[must_use]
-    pub const fn root_best_move(&self) -> EncodedMove {
+    pub fn root_best_move(&self) -> EncodedMove {
         self.pv_table[0][0]
     }
 
     /// Returns the best reply to the best move at the first ply.
     #[must_use]
-    pub const fn root_best_reply(&self) -> EncodedMove {
+    pub fn root_best_reply(&self) -> EncodedMove {
         if self.pv_length[0] >= 2 {
             self.pv_table[0][1]
         } else {
@@ -19,14 +19,67 @@ impl RepetitionTable {
         self.positions.pop().unwrap()
     }
 
-    pub fn contains(&self, zobrist_key: Zobrist) -> bool {
-        self.positions
-            .iter()
-            .rev()
-            .any(|other_key| *other_key == zobrist_key)
+    /// Returns whether the position should be scored as a draw by repetition.
+    ///
+    /// Only positions since the last irreversible move are checked. Repeating a position from
+    /// inside the search tree counts, but positions from before the root need to have occurred
+    /// twice already to make threefold repetition.
+    pub fn is_repetition(
+        &self,
+        zobrist_key: Zobrist,
+        half_move_clock: u32,
+        ply_from_root: u8,
+    ) -> bool {
+        let reversible_plies = (half_move_clock as usize).min(self.positions.len());
+
+        let mut occurred_before_root = false;
+
+        // The same side is to move every 2 plies, and a position can not repeat in less than 4
+        for plies_ago in (4..=reversible_plies).step_by(2) {
+            if self.positions[self.positions.len() - plies_ago] != zobrist_key {
+                continue;
+            }
+
+            if plies_ago < usize::from(ply_from_root) || occurred_before_root {
+                return true;
+            }
+            occurred_before_root = true;
+        }
+
+        false
     }
 
     pub fn clear(&mut self) {
         self.positions.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{repetition_table::RepetitionTable, zobrist::Zobrist};
+
+    fn key(index: usize) -> Zobrist {
+        let mut zobrist_key = Zobrist::EMPTY;
+        zobrist_key.xor_piece(0, index);
+        zobrist_key
+    }
+
+    #[test]
+    fn repetition_follows_chess_rules() {
+        let mut repetition_table = RepetitionTable::new();
+        for index in [1, 2, 3, 4, 1, 2, 3, 4] {
+            repetition_table.push(key(index));
+        }
+
+        // Inside the search tree, once is enough
+        assert!(repetition_table.is_repetition(key(1), 8, 6));
+
+        // Before the root, it needs to have happened twice
+        assert!(repetition_table.is_repetition(key(1), 8, 1));
+        assert!(!repetition_table.is_repetition(key(1), 3, 1));
+        assert!(!repetition_table.is_repetition(key(3), 8, 1));
+
+        // Not before the last irreversible move
+        assert!(!repetition_table.is_repetition(key(1), 7, 1));
+    }
+}
@@ -16,13 +16,35 @@ pub struct Tunable {
     pub lmr_base: u32,
     pub lmr_ply_multiplier: u32,
     pub lmr_index_multiplier: u32,
+    /// How much quiet move history changes the reduction, in 1024ths of a ply.
+    pub lmr_history_divisor: i32,
 
     pub lmp_base: u32,
 
+    pub see_pruning_max_depth: u8,
+    pub see_capture_margin: i32,
+
     pub nmp_min_depth: u8,
     pub nmp_base_reduction: u8,
     pub nmp_ply_divisor: u8,
 
+    /// Greatest depth that razoring is done at.
+    pub razoring_max_depth: u8,
+    /// How far below alpha, per ply, the static evaluation must be to drop into quiescence search.
+    pub razoring_margin: i32,
+
+    /// Least depth that probabilistic cut is done at.
+    pub probcut_min_depth: u8,
+    /// How much depth the verification search of a probabilistic cut is reduced by.
+    pub probcut_depth_reduction: u8,
+    /// How far above beta a capture must score for a probabilistic cut.
+    pub probcut_margin: i32,
+
+    /// Least depth that the hash move is checked for singularity at.
+    pub singular_min_depth: u8,
+    /// How far below the hash move score, per ply, every other move must be for it to be singular.
+    pub singular_margin: i32,
+
     pub aspiration_window_start: i32,
     pub aspiration_window_growth: i32,
     /// Maximum number of aspiration window attempts.
@@ -51,10 +73,23 @@ pub(crate) const DEFAULT_TUNABLES: Tunable = Tunable {
 
     lmp_base: 2,
 
+    see_pruning_max_depth: 6,
+    see_capture_margin: 100,
+
     nmp_min_depth: 2,
     nmp_base_reduction: 3,
     nmp_ply_divisor: 4,
 
+    razoring_max_depth: 3,
+    razoring_margin: 300,
+
+    probcut_min_depth: 5,
+    probcut_depth_reduction: 4,
+    probcut_margin: 200,
+
+    singular_min_depth: 7,
+    singular_margin: 2,
+
     futility_margin: 116,
     static_null_margin: 58,
 
@@ -62,6 +97,7 @@ pub(crate) const DEFAULT_TUNABLES: Tunable = Tunable {
 
     lmr_ply_multiplier: 130,
     lmr_index_multiplier: 100,
+    lmr_history_divisor: 16,
 
     lmr_min_index: 6,
     lmr_min_depth: 3,
@@ -0,0 +1,110 @@
+//! Information about each ply of the line being searched.
+
+use core::ops::{Index, IndexMut};
+
+use crate::board::{piece::Piece, square::Square};
+
+use super::{Ply, encoded_move::EncodedMove, eval_data::EvalNumber};
+
+/// What is known about a position on the line being searched.
+#[derive(Clone, Copy)]
+pub struct Frame {
+    /// Corrected static evaluation, or the one from two plies ago if in check.
+    pub static_eval: EvalNumber,
+
+    /// Quiet move that last caused a beta cutoff at this ply.
+    pub killer_move: EncodedMove,
+
+    /// Move being searched from this position, or [`EncodedMove::NONE`] for a null move.
+    pub current_move: EncodedMove,
+
+    /// Piece moved by `current_move` and the square it moved to.
+    pub moved_piece: Option<(Piece, Square)>,
+
+    /// Move skipped in this position, or [`EncodedMove::NONE`]. Used to check whether the hash move is singular.
+    pub excluded_move: EncodedMove,
+}
+
+impl Frame {
+    const EMPTY: Self = Self {
+        static_eval: 0,
+        killer_move: EncodedMove::NONE,
+        current_move: EncodedMove::NONE,
+        moved_piece: None,
+        excluded_move: EncodedMove::NONE,
+    };
+}
+
+/// A frame for every ply from the root.
+pub struct SearchStack(Box<[Frame; Ply::MAX as usize + 1]>);
+
+impl SearchStack {
+    pub fn new() -> Self {
+        Self(Box::new([Frame::EMPTY; Ply::MAX as usize + 1]))
+    }
+
+    /// Forgets every frame before a new search.
+    pub fn clear(&mut self) {
+        self.0.fill(Frame::EMPTY);
+    }
+
+    /// Returns the frame `plies_ago` plies before `ply_from_root`, or `None` if it is before the root.
+    pub fn previous(&self, ply_from_root: Ply, plies_ago: Ply) -> Option<&Frame> {
+        ply_from_root
+            .checked_sub(plies_ago)
+            .map(|ply| &self.0[ply as usize])
+    }
+}
+
+impl Index<Ply> for SearchStack {
+    type Output = Frame;
+
+    fn index(&self, ply_from_root: Ply) -> &Frame {
+        &self.0[ply_from_root as usize]
+    }
+}
+
+impl IndexMut<Ply> for SearchStack {
+    fn index_mut(&mut self, ply_from_root: Ply) -> &mut Frame {
+        &mut self.0[ply_from_root as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::square::Square,
+        move_generator::move_data::{Flag, Move},
+        search::{Ply, encoded_move::EncodedMove, search_stack::SearchStack},
+    };
+
+    #[test]
+    fn every_ply_has_its_own_frame() {
+        let mut stack = SearchStack::new();
+        let killer = |ply: Ply| {
+            let from = i8::try_from(ply % 64).unwrap();
+            EncodedMove::new(Move {
+                from: Square::from_index(from),
+                to: Square::from_index(from ^ 8),
+                flag: Flag::None,
+            })
+        };
+
+        // Deeper than the 64 plies the killer moves used to be kept for
+        for ply in 0..=Ply::MAX {
+            stack[ply].killer_move = killer(ply);
+            stack[ply].static_eval = i32::from(ply);
+        }
+        for ply in [0, 63, 64, 100, Ply::MAX] {
+            assert!(stack[ply].killer_move == killer(ply));
+        }
+        assert_eq!(
+            stack.previous(100, 2).map(|frame| frame.static_eval),
+            Some(98)
+        );
+        assert!(stack.previous(1, 2).is_none());
+
+        stack.clear();
+        assert!(stack[100].killer_move.is_none());
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
 };
 
-use crate::{evaluation::eval_data::EvalNumber, timer::Time};
+use crate::{evaluation::eval_data::EvalNumber, timer::Clock};
 
 use super::{IMMEDIATE_CHECKMATE_SCORE, Ply, Search};
 
@@ -20,6 +20,8 @@ pub struct TimeManager<'a> {
 
     stopped: Bool,
     pondering: Bool,
+
+    /// Stops the search once the side to move is found to mate within this many plies.
     mated_in: Option<Ply>,
 }
 
@@ -38,12 +40,12 @@ impl NodeLimit {
 }
 
 pub struct RealTime<'a> {
-    timer: &'a Time,
+    timer: &'a dyn Clock,
     hard_time_limit: u64,
     soft_time_limit: u64,
 }
 impl<'a> RealTime<'a> {
-    pub fn new(timer: &'a Time, hard_time_limit: u64, soft_time_limit: u64) -> Self {
+    pub fn new(timer: &'a dyn Clock, hard_time_limit: u64, soft_time_limit: u64) -> Self {
         assert!(hard_time_limit >= soft_time_limit);
         Self {
             timer,
@@ -215,12 +217,18 @@ impl<'a> TimeManager<'a> {
         return self.stopped.load(Ordering::SeqCst);
     }
 
+    /// Returns whether another iteration of iterative deepening should not be started.
+    ///
+    /// `best_move_nodes` is how many of the `node_count` nodes were spent under the best root move, and
+    /// `score_drop` is how much the best score fell over the last few iterations.
     #[must_use]
     pub fn soft_stop(
         &self,
         node_count: u64,
         best_score: EvalNumber,
         best_move_stability: Ply,
+        best_move_nodes: u64,
+        score_drop: EvalNumber,
     ) -> bool {
         if self.is_stopped() {
             return true;
@@ -236,22 +244,105 @@ impl<'a> TimeManager<'a> {
             return true;
         }
 
-        if let Some(ply) = self.mated_in {
-            if Search::score_is_checkmate(best_score) {
-                if EvalNumber::from(ply) == IMMEDIATE_CHECKMATE_SCORE - best_score.abs() {
-                    return true;
-                }
-            }
+        if let Some(ply) = self.mated_in
+            && best_score > 0
+            && Search::score_is_checkmate(best_score)
+            && IMMEDIATE_CHECKMATE_SCORE - best_score <= EvalNumber::from(ply)
+        {
+            // Found a mate at least as fast as the one asked for
+            return true;
         }
 
         if let Some(real_time) = &self.real_time {
             const BEST_MOVE_STABILITY_MULTIPLIERS: [u64; 8] = [150, 130, 120, 110, 100, 95, 90, 85];
-            let multiplier = BEST_MOVE_STABILITY_MULTIPLIERS
+            let stability_multiplier = BEST_MOVE_STABILITY_MULTIPLIERS
                 [best_move_stability.min(BEST_MOVE_STABILITY_MULTIPLIERS.len() as u8 - 1) as usize];
-            let adjusted_time = (real_time.soft_time_limit * multiplier) / 100;
+
+            // Less time is needed when the best move took most of the search
+            let best_move_percentage = (best_move_nodes * 100)
+                .checked_div(node_count)
+                .map_or(50, |percentage| percentage.min(100));
+            let node_multiplier = (150 - best_move_percentage) * 135 / 100;
+
+            // More time is needed when the score is falling
+            let score_multiplier = u64::from((100 + score_drop / 2).clamp(90, 140).unsigned_abs());
+
+            let adjusted_time = real_time.soft_time_limit
+                * stability_multiplier
+                * node_multiplier
+                * score_multiplier
+                / (100 * 100 * 100);
             return real_time.timer.milliseconds() > adjusted_time.min(real_time.hard_time_limit);
         }
 
         return false;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    };
+
+    use crate::{search::IMMEDIATE_CHECKMATE_SCORE, timer::Clock};
+
+    use super::{RealTime, TimeManager};
+
+    struct MockClock(AtomicU64);
+    impl MockClock {
+        fn set(&self, milliseconds: u64) {
+            self.0.store(milliseconds, Ordering::Relaxed);
+        }
+    }
+    impl Clock for MockClock {
+        fn milliseconds(&self) -> u64 {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn soft_limit_depends_on_best_move_certainty() {
+        let clock = MockClock(AtomicU64::new(0));
+        let time_manager = TimeManager::time_limited(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            None,
+            Some(RealTime::new(&clock, 1000, 100)),
+        );
+
+        clock.set(80);
+        // A stable best move that took most of the nodes stops before the soft limit
+        assert!(time_manager.soft_stop(1000, 0, 7, 950, 0));
+        // An unclear best move with a falling score continues past the soft limit
+        assert!(!time_manager.soft_stop(1000, 0, 0, 200, 60));
+
+        clock.set(150);
+        assert!(!time_manager.soft_stop(1000, 0, 0, 200, 60));
+        clock.set(400);
+        assert!(time_manager.soft_stop(1000, 0, 0, 200, 60));
+
+        // The hard limit is never exceeded
+        clock.set(1001);
+        assert!(time_manager.soft_stop(1000, 0, 0, 0, 1000));
+    }
+
+    #[test]
+    fn mate_search_stops_at_a_mate_within_the_limit() {
+        // Mate in 3 moves
+        let time_manager = TimeManager::infinite(
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(false)),
+            Some(5),
+        );
+
+        assert!(!time_manager.soft_stop(1000, 500, 0, 0, 0));
+        assert!(!time_manager.soft_stop(1000, IMMEDIATE_CHECKMATE_SCORE - 7, 0, 0, 0));
+        assert!(time_manager.soft_stop(1000, IMMEDIATE_CHECKMATE_SCORE - 5, 0, 0, 0));
+        assert!(time_manager.soft_stop(1000, IMMEDIATE_CHECKMATE_SCORE - 1, 0, 0, 0));
+
+        // Being mated does not count
+        assert!(!time_manager.soft_stop(1000, -IMMEDIATE_CHECKMATE_SCORE + 4, 0, 0, 0));
+    }
+}
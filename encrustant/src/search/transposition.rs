@@ -1,10 +1,14 @@
 //! Transposition table utilities.
 
-use super::{Ply, encoded_move::EncodedMove, eval_data::EvalNumber};
+use core::sync::atomic::{AtomicU8, AtomicU64, Ordering};
+
+use super::{
+    Ply, TABLEBASE_WIN_SCORE, encoded_move::EncodedMove, eval_cache, eval_data::EvalNumber,
+    zobrist::Zobrist,
+};
 
 #[derive(Clone, Copy)]
 pub(super) struct NodeValue {
-    pub zobrist_key_32: u32,
     pub ply_remaining: Ply,
     pub node_type: NodeType,
     pub value: EvalNumber,
@@ -13,6 +17,69 @@ pub(super) struct NodeValue {
     pub transposition_move: EncodedMove,
 }
 
+/// Scores at least this far from zero are checkmate or tablebase scores, which depend on the ply they are found at.
+const DECISIVE_SCORE: EvalNumber = TABLEBASE_WIN_SCORE - Ply::MAX as EvalNumber;
+
+/// Converts a score counting plies from the root into one counting plies from the position.
+///
+/// The same position can be reached at a different ply, so decisive scores are saved this way.
+const fn value_to_saved(value: EvalNumber, ply_from_root: Ply) -> EvalNumber {
+    if value >= DECISIVE_SCORE {
+        value + ply_from_root as EvalNumber
+    } else if value <= -DECISIVE_SCORE {
+        value - ply_from_root as EvalNumber
+    } else {
+        value
+    }
+}
+
+/// Reverses `value_to_saved` for the ply the position is reached at now.
+const fn value_from_saved(value: EvalNumber, ply_from_root: Ply) -> EvalNumber {
+    if value >= DECISIVE_SCORE {
+        value - ply_from_root as EvalNumber
+    } else if value <= -DECISIVE_SCORE {
+        value + ply_from_root as EvalNumber
+    } else {
+        value
+    }
+}
+
+/// How many different generations can be told apart.
+const GENERATION_COUNT: u8 = 64;
+
+impl NodeValue {
+    /// Packs the node value and the generation it was saved in into 64 bits.
+    #[allow(clippy::cast_sign_loss)]
+    const fn pack(self, generation: u8) -> u64 {
+        (self.value as u32 as u64)
+            | ((self.transposition_move.data() as u64) << 32)
+            | ((self.ply_remaining as u64) << 48)
+            | ((self.node_type as u64) << 56)
+            | ((generation as u64) << 58)
+    }
+
+    /// Returns the generation packed by `pack`.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn unpack_generation(data: u64) -> u8 {
+        (data >> 58) as u8
+    }
+
+    /// Unpacks a node value packed by `pack`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    const fn unpack(data: u64) -> Self {
+        Self {
+            value: data as u32 as EvalNumber,
+            transposition_move: EncodedMove::from_data((data >> 32) as u16),
+            ply_remaining: (data >> 48) as Ply,
+            node_type: match (data >> 56) & 0b11 {
+                0 => NodeType::Exact,
+                1 => NodeType::Beta,
+                _ => NodeType::Alpha,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(super) enum NodeType {
     Exact,
@@ -24,11 +91,286 @@ pub(super) enum NodeType {
     Alpha,
 }
 
+/// One slot of the transposition table.
+///
+/// `key` holds the zobrist key XOR `data`, so an entry torn by two threads writing at once
+/// will not match either position.
+struct Entry {
+    key: AtomicU64,
+    data: AtomicU64,
+}
+
+impl Entry {
+    const fn empty() -> Self {
+        Self {
+            key: AtomicU64::new(0),
+            data: AtomicU64::new(0),
+        }
+    }
+
+    /// Loads the packed data, if the entry is of the position.
+    fn load(&self, zobrist_key: Zobrist) -> Option<u64> {
+        let data = self.data.load(Ordering::Relaxed);
+        let key = self.key.load(Ordering::Relaxed);
+
+        // Check if it's actually the same position
+        if key ^ data == zobrist_key.value() {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    fn save(&self, zobrist_key: Zobrist, data: u64) {
+        self.key
+            .store(zobrist_key.value() ^ data, Ordering::Relaxed);
+        self.data.store(data, Ordering::Relaxed);
+    }
+}
+
+/// How many entries are in one bucket.
+const ENTRIES_PER_BUCKET: usize = 4;
+
+/// Entries that a position could be saved in, sized to fit a cache line.
+#[repr(align(64))]
+struct Bucket([Entry; ENTRIES_PER_BUCKET]);
+
+/// Lock-free transposition table, shareable between search threads.
+pub struct TranspositionTable {
+    buckets: Box<[Bucket]>,
+
+    /// Increased every search, so that entries from old searches are replaced first.
+    generation: AtomicU8,
+}
+
+impl TranspositionTable {
+    /// Creates an empty transposition table with space for `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buckets: (0..(capacity / ENTRIES_PER_BUCKET).max(1))
+                .map(|_| Bucket([const { Entry::empty() }; ENTRIES_PER_BUCKET]))
+                .collect(),
+            generation: AtomicU8::new(0),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn bucket(&self, zobrist_key: Zobrist) -> &Bucket {
+        &self.buckets[zobrist_key.distribute(self.buckets.len()) as usize]
+    }
+
+    /// Returns how many searches ago `data` was saved.
+    fn age(&self, data: u64) -> u8 {
+        self.generation
+            .load(Ordering::Relaxed)
+            .wrapping_sub(NodeValue::unpack_generation(data))
+            % GENERATION_COUNT
+    }
+
+    /// Marks the start of another search, making existing entries older.
+    pub fn new_search(&self) {
+        let generation = self.generation.load(Ordering::Relaxed);
+        self.generation
+            .store((generation + 1) % GENERATION_COUNT, Ordering::Relaxed);
+    }
+
+    /// Returns the saved value of the position reached at `ply_from_root`, if there is one.
+    #[must_use]
+    pub(super) fn get(&self, zobrist_key: Zobrist, ply_from_root: Ply) -> Option<NodeValue> {
+        self.bucket(zobrist_key)
+            .0
+            .iter()
+            .find_map(|entry| entry.load(zobrist_key))
+            .map(|data| {
+                let node_value = NodeValue::unpack(data);
+                NodeValue {
+                    value: value_from_saved(node_value.value, ply_from_root),
+                    ..node_value
+                }
+            })
+    }
+
+    /// Saves the value of the position reached at `ply_from_root`.
+    ///
+    /// If the position is already in its bucket, that entry is updated unless it was searched
+    /// much deeper this search. Otherwise the entry with the lowest depth, counting older
+    /// entries as shallower, is replaced.
+    pub(super) fn store(&self, zobrist_key: Zobrist, node_value: NodeValue, ply_from_root: Ply) {
+        let entries = &self.bucket(zobrist_key).0;
+        let data = NodeValue {
+            value: value_to_saved(node_value.value, ply_from_root),
+            ..node_value
+        }
+        .pack(self.generation.load(Ordering::Relaxed));
+
+        for entry in entries {
+            if let Some(old_data) = entry.load(zobrist_key) {
+                if matches!(node_value.node_type, NodeType::Exact)
+                    || node_value.ply_remaining + 3 >= NodeValue::unpack(old_data).ply_remaining
+                    || self.age(old_data) != 0
+                {
+                    entry.save(zobrist_key, data);
+                }
+                return;
+            }
+        }
+
+        let replacement_value = |entry: &Entry| {
+            let old_data = entry.data.load(Ordering::Relaxed);
+            i16::from(NodeValue::unpack(old_data).ply_remaining) - 8 * i16::from(self.age(old_data))
+        };
+        let replaced = entries
+            .iter()
+            .min_by_key(|entry| replacement_value(entry))
+            .unwrap();
+        replaced.save(zobrist_key, data);
+    }
+
+    /// Hints to the processor that the bucket of the position will be read soon.
+    pub fn prefetch(&self, zobrist_key: Zobrist) {
+        #[cfg(target_feature = "sse")]
+        {
+            use core::arch::x86_64::{_MM_HINT_NTA, _mm_prefetch};
+            unsafe {
+                _mm_prefetch::<{ _MM_HINT_NTA }>(
+                    core::ptr::from_ref(self.bucket(zobrist_key)).cast::<i8>(),
+                );
+            }
+        }
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        {
+            use core::arch::aarch64::{_PREFETCH_LOCALITY0, _PREFETCH_READ, _prefetch};
+            unsafe {
+                _prefetch::<_PREFETCH_READ, _PREFETCH_LOCALITY0>(
+                    core::ptr::from_ref(self.bucket(zobrist_key)).cast::<i8>(),
+                );
+            }
+        }
+        #[cfg(not(any(
+            target_feature = "sse",
+            target_arch = "aarch64",
+            target_arch = "arm64ec"
+        )))]
+        let _ = zobrist_key;
+    }
+
+    /// Empties every entry.
+    pub fn clear(&self) {
+        for bucket in &self.buckets {
+            for entry in &bucket.0 {
+                entry.save(Zobrist::EMPTY, 0);
+            }
+        }
+        self.generation.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns how many entries the table has.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buckets.len() * ENTRIES_PER_BUCKET
+    }
+}
+
 /// How many bytes one transposition table entry takes.
-pub const MEMORY_OF_ONE_ENTRY_IN_BYTES: usize = core::mem::size_of::<Option<NodeValue>>();
+pub const MEMORY_OF_ONE_ENTRY_IN_BYTES: usize = core::mem::size_of::<Entry>();
 
-/// Returns how many transposition table entries could fit into `megabytes` megabytes.
+/// Returns how many transposition table entries could fit into `megabytes` megabytes,
+/// along with the evaluation cache sized from it.
 #[must_use]
 pub const fn megabytes_to_capacity(megabytes: usize) -> usize {
-    (megabytes * 1_000_000) / MEMORY_OF_ONE_ENTRY_IN_BYTES
+    (megabytes * 1_000_000)
+        / (MEMORY_OF_ONE_ENTRY_IN_BYTES + eval_cache::MEMORY_PER_TRANSPOSITION_ENTRY_IN_BYTES)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{
+        IMMEDIATE_CHECKMATE_SCORE,
+        encoded_move::EncodedMove,
+        transposition::{ENTRIES_PER_BUCKET, NodeType, NodeValue, TranspositionTable},
+        zobrist::Zobrist,
+    };
+
+    fn node_value(ply_remaining: u8) -> NodeValue {
+        NodeValue {
+            ply_remaining,
+            node_type: NodeType::Beta,
+            value: -1234,
+            transposition_move: EncodedMove::NONE,
+        }
+    }
+
+    #[test]
+    fn old_entries_are_replaced_first() {
+        // Only one bucket, so every position competes for the same entries
+        let transposition_table = TranspositionTable::new(ENTRIES_PER_BUCKET);
+        let keys: Vec<Zobrist> = (1..=ENTRIES_PER_BUCKET as u64 + 1)
+            .map(|key| {
+                let mut zobrist = Zobrist::EMPTY;
+                zobrist.xor_piece(0, key as usize);
+                zobrist
+            })
+            .collect();
+
+        transposition_table.store(keys[0], node_value(10), 0);
+        transposition_table.new_search();
+        for key in &keys[1..ENTRIES_PER_BUCKET] {
+            transposition_table.store(*key, node_value(5), 0);
+        }
+
+        let saved = transposition_table.get(keys[0], 0).unwrap();
+        assert_eq!(saved.value, -1234);
+        assert_eq!(saved.ply_remaining, 10);
+
+        // The deep entry is from an old search so it is replaced
+        for _ in 0..2 {
+            transposition_table.new_search();
+        }
+        transposition_table.store(keys[ENTRIES_PER_BUCKET], node_value(1), 0);
+        assert!(transposition_table.get(keys[0], 0).is_none());
+        assert!(
+            transposition_table
+                .get(keys[ENTRIES_PER_BUCKET], 0)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn mate_scores_are_relative_to_the_position() {
+        let transposition_table = TranspositionTable::new(ENTRIES_PER_BUCKET);
+        let mut zobrist_key = Zobrist::EMPTY;
+        zobrist_key.xor_piece(0, 1);
+
+        // Mating in two plies from a position reached at ply 3
+        transposition_table.store(
+            zobrist_key,
+            NodeValue {
+                value: IMMEDIATE_CHECKMATE_SCORE - 5,
+                ..node_value(4)
+            },
+            3,
+        );
+        // Is still mating in two plies when reached at ply 7
+        let saved = transposition_table.get(zobrist_key, 7).unwrap();
+        assert_eq!(saved.value, IMMEDIATE_CHECKMATE_SCORE - 9);
+
+        transposition_table.store(
+            zobrist_key,
+            NodeValue {
+                value: -IMMEDIATE_CHECKMATE_SCORE + 6,
+                ..node_value(4)
+            },
+            6,
+        );
+        let saved = transposition_table.get(zobrist_key, 1).unwrap();
+        assert_eq!(saved.value, -IMMEDIATE_CHECKMATE_SCORE + 1);
+
+        // Other scores do not depend on the ply
+        transposition_table.store(zobrist_key, node_value(4), 6);
+        assert_eq!(
+            transposition_table.get(zobrist_key, 1).unwrap().value,
+            -1234
+        );
+    }
 }
@@ -53,6 +53,12 @@ impl Zobrist {
         self.0 ^= ZOBRIST_RANDOMS.side_to_move;
     }
 
+    /// Returns the hash as an integer.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
     /// Take lower 32 bits from hash.
     #[must_use]
     pub const fn lower_u32(&self) -> u32 {
@@ -0,0 +1,494 @@
+//! Syzygy endgame tablebase probing.
+
+mod table;
+
+use core::cmp::Ordering;
+use std::{collections::HashMap, fs, path::PathBuf, sync::OnceLock};
+
+use table::{MAX_PIECES, Material, Probe, Table, TableKind};
+
+use crate::{
+    board::{Board, piece::Piece},
+    move_generator::{
+        MoveGenerator,
+        move_data::{Flag, Move},
+    },
+};
+
+/// Win/draw/loss of a position for the side to move.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Wdl {
+    /// Lost.
+    Loss = -2,
+
+    /// Lost, but drawn by the fifty-move rule.
+    BlessedLoss = -1,
+
+    /// Drawn.
+    Draw = 0,
+
+    /// Won, but drawn by the fifty-move rule.
+    CursedWin = 1,
+
+    /// Won.
+    Win = 2,
+}
+
+impl Wdl {
+    const fn from_value(value: i32) -> Self {
+        match value {
+            ..=-2 => Self::Loss,
+            -1 => Self::BlessedLoss,
+            0 => Self::Draw,
+            1 => Self::CursedWin,
+            2.. => Self::Win,
+        }
+    }
+}
+
+/// How much a root move is preferred if it wins within the fifty-move rule.
+const MAX_DTZ: i32 = 1 << 18;
+
+/// Returns the distance to zeroing of the move before a zeroing move that gives `wdl`.
+const fn dtz_before_zeroing(wdl: i32) -> i32 {
+    match wdl {
+        2 => 1,
+        1 => 101,
+        -1 => -101,
+        -2 => -1,
+        _ => 0,
+    }
+}
+
+/// Packs how many of each piece other than the king each side has.
+fn material_key(white: [u8; 6], black: [u8; 6]) -> u64 {
+    let mut key = 0;
+    for piece in 0..5 {
+        key |= u64::from(white[piece]) << (piece * 4);
+        key |= u64::from(black[piece]) << (20 + piece * 4);
+    }
+    key
+}
+
+fn piece_counts(board: &Board) -> ([u8; 6], [u8; 6]) {
+    #[allow(clippy::cast_possible_truncation)]
+    let count = |piece: Piece| board.get_bit_board(piece).count() as u8;
+    (
+        Piece::WHITE_PIECES.map(count),
+        Piece::BLACK_PIECES.map(count),
+    )
+}
+
+/// Parses a table name like `KRPvKR` into the pieces of each side.
+fn parse_table_name(name: &str) -> Option<([u8; 6], [u8; 6])> {
+    let (white, black) = name.split_once('v')?;
+    let parse_side = |side: &str| {
+        let mut counts = [0u8; 6];
+        let mut pieces = side.chars();
+        if pieces.next()? != 'K' {
+            return None;
+        }
+        for piece in pieces {
+            counts["PNBRQ".find(piece)?] += 1;
+        }
+        counts[5] = 1;
+        Some(counts)
+    };
+    Some((parse_side(white)?, parse_side(black)?))
+}
+
+fn legal_moves(board: &Board) -> Vec<Move> {
+    let mut moves = Vec::new();
+    MoveGenerator::new(board).generate(|move_data| moves.push(move_data), false);
+    moves
+}
+
+fn is_capture(board: &Board, move_data: Move) -> bool {
+    move_data.flag == Flag::EnPassant || board.enemy_piece_at(move_data.to).is_some()
+}
+
+fn is_zeroing(board: &Board, move_data: Move) -> bool {
+    is_capture(board, move_data)
+        || matches!(
+            board.friendly_piece_at(move_data.from),
+            Some(Piece::WhitePawn | Piece::BlackPawn)
+        )
+}
+
+fn is_checkmate(board: &Board) -> bool {
+    MoveGenerator::calculate_is_in_check(board) && legal_moves(board).is_empty()
+}
+
+struct TableEntry {
+    material: Material,
+    wdl_path: PathBuf,
+    dtz_path: PathBuf,
+
+    /// Loaded on first probe.
+    wdl: OnceLock<Option<Table>>,
+    dtz: OnceLock<Option<Table>>,
+}
+
+/// Syzygy tablebase files, which are loaded when first probed.
+#[derive(Default)]
+pub struct Tablebase {
+    tables: Vec<TableEntry>,
+
+    /// Index of the table by both of its material keys.
+    table_indices: HashMap<u64, usize>,
+
+    max_pieces: usize,
+}
+
+impl Tablebase {
+    /// Finds the tables in `paths`, which are separated by `:`, or `;` on Windows.
+    #[must_use]
+    pub fn new(paths: &str) -> Self {
+        let mut tablebase = Self::default();
+        let separator = if cfg!(windows) { ';' } else { ':' };
+
+        for directory in paths.split(separator).filter(|path| !path.is_empty()) {
+            let Ok(files) = fs::read_dir(directory) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "rtbw")
+                {
+                    tablebase.add(path);
+                }
+            }
+        }
+
+        tablebase
+    }
+
+    fn add(&mut self, wdl_path: PathBuf) {
+        let Some(name) = wdl_path.file_stem().and_then(|name| name.to_str()) else {
+            return;
+        };
+        let Some((white, black)) = parse_table_name(name) else {
+            return;
+        };
+
+        let key = material_key(white, black);
+        if self.table_indices.contains_key(&key) {
+            return;
+        }
+
+        let piece_count = white
+            .iter()
+            .chain(&black)
+            .map(|&count| usize::from(count))
+            .sum();
+        if piece_count > MAX_PIECES {
+            return;
+        }
+
+        let has_unique_pieces = white[..5].contains(&1) || black[..5].contains(&1);
+
+        // The side with fewer pawns leads, as it compresses better
+        let white_leads = black[0] == 0 || (white[0] != 0 && black[0] >= white[0]);
+        let pawn_count = if white_leads {
+            [white[0], black[0]]
+        } else {
+            [black[0], white[0]]
+        };
+
+        let mirrored_key = material_key(black, white);
+        let index = self.tables.len();
+        self.tables.push(TableEntry {
+            material: Material {
+                key,
+                mirrored_key,
+                piece_count,
+                has_pawns: white[0] + black[0] != 0,
+                has_unique_pieces,
+                pawn_count,
+            },
+            dtz_path: wdl_path.with_extension("rtbz"),
+            wdl_path,
+            wdl: OnceLock::new(),
+            dtz: OnceLock::new(),
+        });
+        self.table_indices.insert(key, index);
+        self.table_indices.insert(mirrored_key, index);
+        self.max_pieces = self.max_pieces.max(piece_count);
+    }
+
+    /// Returns the most pieces of any table found.
+    #[must_use]
+    pub const fn max_pieces(&self) -> usize {
+        self.max_pieces
+    }
+
+    /// Returns how many tables were found.
+    #[must_use]
+    pub const fn table_count(&self) -> usize {
+        self.tables.len()
+    }
+
+    fn probe_table(&self, board: &Board, kind: TableKind, wdl: i32) -> Option<Probe> {
+        let (white, black) = piece_counts(board);
+        if white.iter().chain(&black).sum::<u8>() == 2 {
+            // Only kings
+            return Some(Probe::Value(0));
+        }
+
+        let position_key = material_key(white, black);
+        let entry = &self.tables[*self.table_indices.get(&position_key)?];
+        let table = match kind {
+            TableKind::Wdl => &entry.wdl,
+            TableKind::Dtz => &entry.dtz,
+        }
+        .get_or_init(|| {
+            let path = match kind {
+                TableKind::Wdl => &entry.wdl_path,
+                TableKind::Dtz => &entry.dtz_path,
+            };
+            Table::load(path, kind, &entry.material)
+        })
+        .as_ref()?;
+
+        table.probe(board, &entry.material, position_key, wdl)
+    }
+
+    /// Returns the win/draw/loss, and whether the best move is zeroing.
+    ///
+    /// Tables can store any value for positions where a capture is at least as good, so
+    /// captures are searched too. Pawn moves are also searched if `check_zeroing_moves`,
+    /// because distance to zeroing tables do not store positions where the best move is one.
+    fn search(&self, board: &mut Board, check_zeroing_moves: bool) -> Option<(i32, bool)> {
+        let moves = legal_moves(board);
+        let mut best = -2;
+        let mut searched = 0;
+
+        for move_data in &moves {
+            if !(is_capture(board, *move_data)
+                || (check_zeroing_moves && is_zeroing(board, *move_data)))
+            {
+                continue;
+            }
+            searched += 1;
+
+            let old_state = board.make_move(move_data);
+            let result = self.search(board, false);
+            board.unmake_move(move_data, &old_state);
+
+            let value = -result?.0;
+            if value > best {
+                best = value;
+                if value >= 2 {
+                    return Some((value, true));
+                }
+            }
+        }
+
+        // If every move was searched, the table is not needed, which also handles positions
+        // with en passant that tables do not store
+        let every_move_searched = searched != 0 && searched == moves.len();
+        let value = if every_move_searched {
+            best
+        } else {
+            match self.probe_table(board, TableKind::Wdl, 0)? {
+                Probe::Value(value) => value,
+                Probe::OtherSideToMove => unreachable!(),
+            }
+        };
+
+        if best >= value {
+            Some((best, best > 0 || every_move_searched))
+        } else {
+            Some((value, false))
+        }
+    }
+
+    /// Returns the win/draw/loss of the position, or `None` if it is not in the tables.
+    ///
+    /// The fifty-move counter is assumed to be zero.
+    #[must_use]
+    pub fn probe_wdl(&self, board: &mut Board) -> Option<Wdl> {
+        if !board.game_state.castling_rights.is_none() {
+            return None;
+        }
+        self.search(board, false)
+            .map(|(value, _)| Wdl::from_value(value))
+    }
+
+    /// Returns the distance in plies to the next capture or pawn move, positive if winning and
+    /// negative if losing, or `None` if the position is not in the tables.
+    ///
+    /// Wins and losses drawn by the fifty-move rule are 100 further.
+    #[must_use]
+    pub fn probe_dtz(&self, board: &mut Board) -> Option<i32> {
+        if !board.game_state.castling_rights.is_none() {
+            return None;
+        }
+        self.dtz(board)
+    }
+
+    fn dtz(&self, board: &mut Board) -> Option<i32> {
+        let (wdl, best_move_is_zeroing) = self.search(board, true)?;
+        if wdl == 0 {
+            return Some(0);
+        }
+        if best_move_is_zeroing {
+            return Some(dtz_before_zeroing(wdl));
+        }
+
+        match self.probe_table(board, TableKind::Dtz, wdl)? {
+            Probe::Value(dtz) => {
+                let fifty_move_draw = wdl == 1 || wdl == -1;
+                Some((dtz + if fifty_move_draw { 100 } else { 0 }) * wdl.signum())
+            }
+            Probe::OtherSideToMove => {
+                // Find the move with the lowest distance to zeroing
+                let mut min_dtz = i32::MAX;
+                for move_data in legal_moves(board) {
+                    let zeroing = is_zeroing(board, move_data);
+
+                    let old_state = board.make_move(&move_data);
+                    let dtz = if zeroing {
+                        self.search(board, false)
+                            .map(|(wdl, _)| -dtz_before_zeroing(wdl))
+                    } else {
+                        self.dtz(board).map(|dtz| -dtz)
+                    };
+                    let is_mate = dtz == Some(1) && is_checkmate(board);
+                    board.unmake_move(&move_data, &old_state);
+
+                    let mut dtz = dtz?;
+                    if is_mate {
+                        min_dtz = 1;
+                    }
+                    if !zeroing {
+                        dtz += dtz.signum();
+                    }
+                    if dtz < min_dtz && dtz.signum() == wdl.signum() {
+                        min_dtz = dtz;
+                    }
+                }
+
+                // Checkmated
+                Some(if min_dtz == i32::MAX { -1 } else { min_dtz })
+            }
+        }
+    }
+
+    /// Ranks each legal move by distance to zeroing, so that the moves with the highest rank
+    /// keep the best outcome under the fifty-move rule.
+    ///
+    /// Returns `None` if any move could not be probed.
+    #[must_use]
+    pub fn rank_root_moves(&self, board: &mut Board) -> Option<Vec<(Move, i32)>> {
+        if !board.game_state.castling_rights.is_none() {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_wrap)]
+        let half_move_clock = board.game_state.half_move_clock as i32;
+
+        let mut ranked = Vec::new();
+        for move_data in legal_moves(board) {
+            let old_state = board.make_move(&move_data);
+            let dtz = if board.game_state.half_move_clock == 0 {
+                self.search(board, false)
+                    .map(|(wdl, _)| dtz_before_zeroing(-wdl))
+            } else if board.game_state.half_move_clock >= 100 {
+                Some(0)
+            } else {
+                self.dtz(board).map(|dtz| -dtz - dtz.signum())
+            };
+            let is_mate = dtz == Some(2) && is_checkmate(board);
+            board.unmake_move(&move_data, &old_state);
+
+            let dtz = if is_mate { 1 } else { dtz? };
+            let rank = match dtz.cmp(&0) {
+                Ordering::Greater if dtz + half_move_clock <= 99 => MAX_DTZ,
+                Ordering::Greater => MAX_DTZ - (dtz + half_move_clock),
+                Ordering::Less if -dtz * 2 + half_move_clock < 100 => -MAX_DTZ,
+                Ordering::Less => -MAX_DTZ + (-dtz + half_move_clock),
+                Ordering::Equal => 0,
+            };
+            ranked.push((move_data, rank));
+        }
+        Some(ranked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        tablebase::{MAX_DTZ, Tablebase, Wdl, parse_table_name},
+        uci::encode_move,
+    };
+
+    #[test]
+    fn table_names_are_parsed() {
+        assert_eq!(
+            parse_table_name("KRPvKR"),
+            Some(([1, 0, 0, 1, 0, 1], [0, 0, 0, 1, 0, 1]))
+        );
+        assert_eq!(parse_table_name("KRvR"), None);
+        assert_eq!(parse_table_name("KXvK"), None);
+
+        // Bare kings are always drawn, even without any tables
+        let tablebase = Tablebase::new("");
+        let mut board = Board::from_fen("8/8/8/3k4/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&mut board), Some(Wdl::Draw));
+        let mut board = Board::from_fen("8/8/8/3k4/8/8/8/4KQ2 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&mut board), None);
+    }
+
+    /// Needs the KQvK and KRvK tables, from a directory given by `SYZYGY_TEST_PATH`.
+    #[test]
+    #[ignore = "needs Syzygy tables in SYZYGY_TEST_PATH"]
+    fn real_tables() {
+        let tablebase = Tablebase::new(&std::env::var("SYZYGY_TEST_PATH").unwrap());
+        assert_eq!(tablebase.max_pieces(), 3);
+
+        let probe = |fen| {
+            let mut board = Board::from_fen(fen).unwrap();
+            (
+                tablebase.probe_wdl(&mut board).unwrap(),
+                tablebase.probe_dtz(&mut board).unwrap(),
+            )
+        };
+
+        // Mate in one, and its mirror with the other side to move
+        assert_eq!(probe("7k/8/6K1/8/8/8/8/Q7 w - - 0 1"), (Wdl::Win, 1));
+        let (wdl, dtz) = probe("7K/8/6k1/8/8/8/8/q7 b - - 0 1");
+        assert_eq!((wdl, dtz), (Wdl::Win, 1));
+        let (wdl, dtz) = probe("7k/8/6K1/8/8/8/8/Q7 b - - 0 1");
+        assert_eq!(wdl, Wdl::Loss);
+        assert!(dtz < 0);
+        let (wdl, dtz) = probe("q7/8/8/8/8/6k1/8/7K w - - 0 1");
+        assert_eq!(wdl, Wdl::Loss);
+        assert!(dtz < 0);
+
+        // Stalemate
+        assert_eq!(probe("k7/8/1QK5/8/8/8/8/8 b - - 0 1"), (Wdl::Draw, 0));
+
+        assert_eq!(probe("k7/8/1K6/8/8/8/8/7R w - - 0 1"), (Wdl::Win, 1));
+
+        // The rook is lost
+        assert_eq!(probe("8/8/8/8/8/8/1k6/R3K3 b - - 0 1").0, Wdl::Draw);
+
+        // Mating is ranked as a win, and stalemating as a draw
+        let mut board = Board::from_fen("k7/8/2K5/8/8/8/8/1Q6 w - - 0 1").unwrap();
+        let ranked = tablebase.rank_root_moves(&mut board).unwrap();
+        let rank = |uci: &str| {
+            ranked
+                .iter()
+                .find(|&&(move_data, _)| encode_move(move_data) == uci)
+                .map(|&(_, rank)| rank)
+                .unwrap()
+        };
+        assert_eq!(rank("b1b7"), MAX_DTZ);
+        assert_eq!(rank("b1b6"), 0);
+        assert!(ranked.iter().all(|&(_, rank)| rank >= 0));
+    }
+}
@@ -0,0 +1,1168 @@
+//! Decoding of a single Syzygy table file.
+
+use std::{fs, path::Path};
+
+use crate::board::Board;
+
+/// Most pieces a table can have.
+pub const MAX_PIECES: usize = 7;
+
+/// Whether a table stores win/draw/loss or distance to zeroing.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    Wdl,
+    Dtz,
+}
+
+const WDL_MAGIC: [u8; 4] = [0x71, 0xE8, 0x23, 0x5D];
+const DTZ_MAGIC: [u8; 4] = [0xD7, 0x66, 0x0C, 0xA5];
+
+// Flags of `PairsData`
+const SIDE_TO_MOVE: u8 = 1;
+const MAPPED: u8 = 2;
+const WIN_PLIES: u8 = 4;
+const LOSS_PLIES: u8 = 8;
+const WIDE: u8 = 16;
+const SINGLE_VALUE: u8 = 128;
+
+/// Material of a table, which decides how its positions are indexed.
+pub struct Material {
+    /// Material key with the stronger side as white.
+    pub key: u64,
+
+    /// Material key with the stronger side as black.
+    pub mirrored_key: u64,
+
+    pub piece_count: usize,
+    pub has_pawns: bool,
+
+    /// Whether any side has exactly one of a piece that is not a king.
+    pub has_unique_pieces: bool,
+
+    /// Pawns of the leading colour, then of the other colour.
+    pub pawn_count: [u8; 2],
+}
+
+impl Material {
+    const fn is_symmetric(&self) -> bool {
+        self.key == self.mirrored_key
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn off_diagonal(square: usize) -> i32 {
+    (square >> 3) as i32 - (square & 7) as i32
+}
+
+const fn are_adjacent(a: usize, b: usize) -> bool {
+    (a >> 3).abs_diff(b >> 3) <= 1 && (a & 7).abs_diff(b & 7) <= 1
+}
+
+/// `BINOMIAL[k][n]` is how many ways `k` elements can be chosen from `n` elements.
+const BINOMIAL: [[u64; 64]; MAX_PIECES] = {
+    let mut binomial = [[0; 64]; MAX_PIECES];
+    binomial[0][0] = 1;
+    let mut n = 1;
+    while n < 64 {
+        let mut k = 0;
+        while k < MAX_PIECES && k <= n {
+            binomial[k][n] = (if k > 0 { binomial[k - 1][n - 1] } else { 0 })
+                + (if k < n { binomial[k][n - 1] } else { 0 });
+            k += 1;
+        }
+        n += 1;
+    }
+    binomial
+};
+
+/// Maps squares below the a1-h8 diagonal to 0..28.
+const MAP_B1H1H7: [u64; 64] = {
+    let mut map = [0; 64];
+    let mut code = 0;
+    let mut square = 0;
+    while square < 64 {
+        if off_diagonal(square) < 0 {
+            map[square] = code;
+            code += 1;
+        }
+        square += 1;
+    }
+    map
+};
+
+/// Maps squares of the a1-d1-d4 triangle to 0..10, with the diagonal last.
+const MAP_A1D1D4: [u64; 64] = {
+    let mut map = [0; 64];
+    let mut code = 0;
+    let mut square = 0;
+    while square <= 27 {
+        if off_diagonal(square) < 0 && square & 7 <= 3 {
+            map[square] = code;
+            code += 1;
+        }
+        square += 1;
+    }
+    square = 0;
+    while square <= 27 {
+        if off_diagonal(square) == 0 && square & 7 <= 3 {
+            map[square] = code;
+            code += 1;
+        }
+        square += 1;
+    }
+    map
+};
+
+/// Maps the 462 legal placements of two kings, with the first in the a1-d1-d4 triangle.
+const MAP_KK: [[u64; 64]; 10] = {
+    let mut map = [[0; 64]; 10];
+    let mut code = 0;
+
+    // Placements with both kings on the diagonal go last
+    let mut both_on_diagonal = false;
+    loop {
+        let mut index = 0;
+        while index < 10 {
+            let mut first = 0;
+            while first <= 27 {
+                // b1 is also mapped to 0
+                if MAP_A1D1D4[first] == index as u64 && (index != 0 || first == 1) {
+                    let mut second = 0;
+                    while second < 64 {
+                        let first_on_diagonal = off_diagonal(first) == 0;
+                        if are_adjacent(first, second)
+                            || (first_on_diagonal && off_diagonal(second) > 0)
+                        {
+                            // Illegal, or the second king is above the diagonal
+                        } else if (first_on_diagonal && off_diagonal(second) == 0)
+                            == both_on_diagonal
+                        {
+                            map[index][second] = code;
+                            code += 1;
+                        }
+                        second += 1;
+                    }
+                }
+                first += 1;
+            }
+            index += 1;
+        }
+        if both_on_diagonal {
+            break;
+        }
+        both_on_diagonal = true;
+    }
+    map
+};
+
+/// Maps a2-h7 so that the pawn with the highest value is the leading pawn, the one nearest
+/// the edge and then with the lowest rank.
+const MAP_PAWNS: [usize; 64] = {
+    let mut map = [0; 64];
+    let mut available_squares: usize = 47;
+    let mut file = 0;
+    while file < 4 {
+        let mut rank = 1;
+        while rank <= 6 {
+            let square = rank * 8 + file;
+            map[square] = available_squares;
+            map[square ^ 7] = available_squares - 1;
+            available_squares = available_squares.saturating_sub(2);
+            rank += 1;
+        }
+        file += 1;
+    }
+    map
+};
+
+/// Index of the leading pawns group by leading pawn count and leading pawn square, then
+/// the size of the group by leading pawn count and file.
+const LEAD_PAWNS: ([[u64; 64]; 6], [[u64; 4]; 6]) = {
+    let mut lead_pawn_index = [[0; 64]; 6];
+    let mut lead_pawns_size = [[0; 4]; 6];
+    let mut lead_pawn_count = 1;
+    while lead_pawn_count <= 5 {
+        let mut file = 0;
+        while file < 4 {
+            let mut index = 0;
+            let mut rank = 1;
+            while rank <= 6 {
+                let square = rank * 8 + file;
+                lead_pawn_index[lead_pawn_count][square] = index;
+                index += BINOMIAL[lead_pawn_count - 1][MAP_PAWNS[square]];
+                rank += 1;
+            }
+            lead_pawns_size[lead_pawn_count][file] = index;
+            file += 1;
+        }
+        lead_pawn_count += 1;
+    }
+    (lead_pawn_index, lead_pawns_size)
+};
+
+// Reads return `None` past the end of the file, so a truncated or corrupt table fails the probe
+
+fn read_u8(data: &[u8], at: usize) -> Option<u8> {
+    data.get(at).copied()
+}
+
+fn read_u16_le(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(at..at.checked_add(2)?)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32_le(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(at..at.checked_add(4)?)?.try_into().ok()?,
+    ))
+}
+
+/// Reads big endian bytes, as zero past the end of the file.
+fn read_be<const N: usize>(data: &[u8], at: usize) -> u64 {
+    (0..N).fold(0, |value, index| {
+        (value << 8) | u64::from(data.get(at + index).copied().unwrap_or(0))
+    })
+}
+
+/// Indexing and compression information of one subtable.
+#[derive(Default)]
+struct PairsData {
+    flags: u8,
+    min_symbol_length: u8,
+    block_size: usize,
+    span: u64,
+
+    /// Offset of the lowest symbol of each length.
+    lowest_symbol: usize,
+
+    /// Offset of the left and right symbols that each symbol expands into.
+    btree: usize,
+
+    /// Offset of how many values (minus one) are in each block.
+    block_length: usize,
+    block_length_size: usize,
+
+    /// Offset of the block and offset in the block of every `span` values.
+    sparse_index: usize,
+    sparse_index_size: usize,
+
+    /// Offset of the compressed blocks.
+    data: usize,
+    block_count: usize,
+
+    /// Lowest symbol of each length, padded to 64 bits.
+    base64: Vec<u64>,
+
+    /// How many values (minus one) each symbol represents.
+    symbol_length: Vec<u8>,
+
+    /// Pieces, ordered into groups.
+    pieces: [u8; MAX_PIECES],
+    group_index: [u64; MAX_PIECES + 1],
+    group_length: [usize; MAX_PIECES + 1],
+
+    /// Where the values of loss, win, cursed win and blessed loss start in the map.
+    map_index: [u16; 4],
+}
+
+impl PairsData {
+    fn left_symbol(&self, data: &[u8], symbol: usize) -> Option<usize> {
+        let at = self.btree + symbol * 3;
+        Some((usize::from(read_u8(data, at + 1)? & 0xF) << 8) | usize::from(read_u8(data, at)?))
+    }
+
+    fn right_symbol(&self, data: &[u8], symbol: usize) -> Option<usize> {
+        let at = self.btree + symbol * 3;
+        Some((usize::from(read_u8(data, at + 2)?) << 4) | usize::from(read_u8(data, at + 1)? >> 4))
+    }
+
+    fn lowest_symbol(&self, data: &[u8], length: usize) -> Option<u64> {
+        Some(u64::from(read_u16_le(
+            data,
+            self.lowest_symbol + length * 2,
+        )?))
+    }
+
+    /// Splits the pieces into groups that are indexed together, and works out what each group
+    /// is multiplied by.
+    fn set_groups(&mut self, material: &Material, order: [u8; 2], file: usize) {
+        let mut group = 0;
+        let mut first_length = if material.has_pawns {
+            0
+        } else if material.has_unique_pieces {
+            3
+        } else {
+            2
+        };
+        self.group_length[0] = 1;
+        for index in 1..material.piece_count {
+            first_length -= 1;
+            if first_length > 0 || self.pieces[index] == self.pieces[index - 1] {
+                self.group_length[group] += 1;
+            } else {
+                group += 1;
+                self.group_length[group] = 1;
+            }
+        }
+        group += 1;
+        self.group_length[group] = 0;
+
+        // Both sides have pawns
+        let pawns_pawns = material.has_pawns && material.pawn_count[1] != 0;
+
+        let mut next = if pawns_pawns { 2 } else { 1 };
+        let mut free_squares =
+            64 - self.group_length[0] - if pawns_pawns { self.group_length[1] } else { 0 };
+        let mut index = 1;
+
+        let mut k = 0;
+        while next < group || k == order[0] || k == order[1] {
+            if k == order[0] {
+                // Leading pawns or pieces
+                self.group_index[0] = index;
+                index *= if material.has_pawns {
+                    LEAD_PAWNS.1[self.group_length[0]][file]
+                } else if material.has_unique_pieces {
+                    31332
+                } else {
+                    462
+                };
+            } else if k == order[1] {
+                // Remaining pawns
+                self.group_index[1] = index;
+                index *= BINOMIAL[self.group_length[1]][48 - self.group_length[0]];
+            } else {
+                self.group_index[next] = index;
+                index *= BINOMIAL[self.group_length[next]][free_squares];
+                free_squares -= self.group_length[next];
+                next += 1;
+            }
+            k += 1;
+        }
+        self.group_index[group] = index;
+    }
+
+    /// Returns how many values (minus one) `symbol` expands into.
+    fn set_symbol_length(
+        &mut self,
+        data: &[u8],
+        symbol: usize,
+        visited: &mut [bool],
+    ) -> Option<u8> {
+        visited[symbol] = true;
+
+        let right = self.right_symbol(data, symbol)?;
+        if right == 0xFFF {
+            return Some(0);
+        }
+        let left = self.left_symbol(data, symbol)?;
+
+        if !*visited.get(left)? {
+            self.symbol_length[left] = self.set_symbol_length(data, left, visited)?;
+        }
+        if !*visited.get(right)? {
+            self.symbol_length[right] = self.set_symbol_length(data, right, visited)?;
+        }
+        Some(
+            self.symbol_length[left]
+                .wrapping_add(self.symbol_length[right])
+                .wrapping_add(1),
+        )
+    }
+
+    /// Reads the Huffman code, returning the offset after it.
+    fn set_sizes(&mut self, data: &[u8], mut at: usize) -> Option<usize> {
+        self.flags = read_u8(data, at)?;
+        at += 1;
+
+        if self.flags & SINGLE_VALUE != 0 {
+            // The single value is stored as the minimum symbol length
+            self.min_symbol_length = read_u8(data, at)?;
+            return Some(at + 1);
+        }
+
+        let table_size = self.group_index[self
+            .group_length
+            .iter()
+            .position(|&length| length == 0)
+            .unwrap()];
+
+        self.block_size = 1_usize.checked_shl(u32::from(read_u8(data, at)?))?;
+        self.span = 1_u64.checked_shl(u32::from(read_u8(data, at + 1)?))?;
+        self.sparse_index_size = usize::try_from(table_size.div_ceil(self.span)).ok()?;
+        let padding = usize::from(read_u8(data, at + 2)?);
+        self.block_count = read_u32_le(data, at + 3)? as usize;
+        self.block_length_size = self.block_count + padding;
+        let max_symbol_length = read_u8(data, at + 7)?;
+        self.min_symbol_length = read_u8(data, at + 8)?;
+        at += 9;
+        self.lowest_symbol = at;
+
+        // Longer symbols have lower values, so that a symbol padded to 64 bits is between the
+        // padded lowest symbols of its length and one less
+        let length_count = usize::from(max_symbol_length.checked_sub(self.min_symbol_length)?) + 1;
+        self.base64 = vec![0; length_count];
+        for length in (0..length_count - 1).rev() {
+            self.base64[length] = self.base64[length + 1]
+                .wrapping_add(self.lowest_symbol(data, length)?)
+                .wrapping_sub(self.lowest_symbol(data, length + 1)?)
+                / 2;
+        }
+        for (length, base) in self.base64.iter_mut().enumerate() {
+            let shift = 64_usize.checked_sub(length + usize::from(self.min_symbol_length))?;
+            *base = base.checked_shl(u32::try_from(shift).ok()?)?;
+        }
+        at += length_count * 2;
+
+        let symbol_count = usize::from(read_u16_le(data, at)?);
+        at += 2;
+        self.symbol_length = vec![0; symbol_count];
+        self.btree = at;
+
+        let mut visited = vec![false; symbol_count];
+        for symbol in 0..symbol_count {
+            if !visited[symbol] {
+                self.symbol_length[symbol] = self.set_symbol_length(data, symbol, &mut visited)?;
+            }
+        }
+
+        Some(at + symbol_count * 3 + (symbol_count & 1))
+    }
+
+    /// Returns the value stored at `index`, or `None` if the table is corrupt.
+    fn decompress(&self, data: &[u8], index: u64) -> Option<usize> {
+        if self.flags & SINGLE_VALUE != 0 {
+            return Some(usize::from(self.min_symbol_length));
+        }
+
+        // The sparse index points to the block and offset of every `span` values, starting
+        // half a span in
+        let sparse_entry = self.sparse_index + usize::try_from(index / self.span).ok()? * 6;
+        let mut block = read_u32_le(data, sparse_entry)? as usize;
+        #[allow(clippy::cast_possible_wrap)]
+        let mut offset = i64::from(read_u16_le(data, sparse_entry + 4)?)
+            + (index % self.span) as i64
+            - (self.span / 2) as i64;
+
+        let block_length = |block: usize| {
+            read_u16_le(data, self.block_length.checked_add(block.checked_mul(2)?)?).map(i64::from)
+        };
+        while offset < 0 {
+            block = block.checked_sub(1)?;
+            offset += block_length(block)? + 1;
+        }
+        while offset > block_length(block)? {
+            offset -= block_length(block)? + 1;
+            block += 1;
+        }
+
+        let mut at = self.data.checked_add(block.checked_mul(self.block_size)?)?;
+        let mut buffer = read_be::<8>(data, at);
+        at += 8;
+        let mut buffer_size: usize = 64;
+
+        let min_symbol_length = usize::from(self.min_symbol_length);
+        let mut symbol;
+        loop {
+            let mut length = 0;
+            while buffer < *self.base64.get(length)? {
+                length += 1;
+            }
+
+            // Symbols of the same length are consecutive
+            let shift = 64_usize.checked_sub(length + min_symbol_length)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let offset_in_length = (buffer - self.base64[length])
+                .checked_shr(u32::try_from(shift).ok()?)
+                .unwrap_or(0) as u16;
+            #[allow(clippy::cast_possible_truncation)]
+            let lowest = self.lowest_symbol(data, length)? as u16;
+            symbol = usize::from(offset_in_length.wrapping_add(lowest));
+
+            let symbol_values = i64::from(*self.symbol_length.get(symbol)?) + 1;
+            if offset < symbol_values {
+                break;
+            }
+            offset -= symbol_values;
+
+            length += min_symbol_length;
+            buffer = buffer.checked_shl(u32::try_from(length).ok()?).unwrap_or(0);
+            buffer_size = buffer_size.checked_sub(length)?;
+            if buffer_size <= 32 {
+                buffer_size += 32;
+                buffer |= read_be::<4>(data, at) << (64 - buffer_size);
+                at += 4;
+            }
+        }
+
+        // Expand the symbol into the pair it replaced until reaching a single value, which can
+        // not take more steps than there are symbols unless the tree has a cycle
+        let mut steps = 0;
+        while self.symbol_length[symbol] != 0 {
+            steps += 1;
+            if steps > self.symbol_length.len() {
+                return None;
+            }
+
+            let left = self.left_symbol(data, symbol)?;
+            let left_values = i64::from(*self.symbol_length.get(left)?) + 1;
+            if offset < left_values {
+                symbol = left;
+            } else {
+                offset -= left_values;
+                symbol = self.right_symbol(data, symbol)?;
+                self.symbol_length.get(symbol)?;
+            }
+        }
+
+        self.left_symbol(data, symbol)
+    }
+}
+
+/// Result of looking up a position in a table.
+pub enum Probe {
+    Value(i32),
+
+    /// The distance to zeroing table only stores the other side to move.
+    OtherSideToMove,
+}
+
+/// A loaded table file.
+pub struct Table {
+    data: Vec<u8>,
+    kind: TableKind,
+
+    /// Subtables by side to move, then by leading pawn file.
+    items: [[PairsData; 4]; 2],
+
+    /// Offset of the distance to zeroing map.
+    map: usize,
+}
+
+impl Table {
+    /// Reads and sets up the table at `path`, or returns `None` if it is not a valid table.
+    pub fn load(path: &Path, kind: TableKind, material: &Material) -> Option<Self> {
+        Self::from_bytes(fs::read(path).ok()?, kind, material)
+    }
+
+    /// Sets up a table from the contents of its file, or returns `None` if it is not a valid table.
+    fn from_bytes(data: Vec<u8>, kind: TableKind, material: &Material) -> Option<Self> {
+        let magic = match kind {
+            TableKind::Wdl => WDL_MAGIC,
+            TableKind::Dtz => DTZ_MAGIC,
+        };
+        if data.get(..4)? != magic {
+            return None;
+        }
+
+        let mut table = Self {
+            data,
+            kind,
+            items: Default::default(),
+            map: 0,
+        };
+        table.set_up(material)?;
+        Some(table)
+    }
+
+    fn set_up(&mut self, material: &Material) -> Option<()> {
+        let data = &self.data;
+        let mut at = 5;
+
+        let sides = if self.kind == TableKind::Wdl && !material.is_symmetric() {
+            2
+        } else {
+            1
+        };
+        let file_count = if material.has_pawns { 4 } else { 1 };
+        let pawns_pawns = material.has_pawns && material.pawn_count[1] != 0;
+
+        for file in 0..file_count {
+            let first_order = read_u8(data, at)?;
+            let second_order = if pawns_pawns {
+                read_u8(data, at + 1)?
+            } else {
+                0xFF
+            };
+            let order = [
+                [first_order & 0xF, second_order & 0xF],
+                [first_order >> 4, second_order >> 4],
+            ];
+            at += 1 + usize::from(pawns_pawns);
+
+            for piece in 0..material.piece_count {
+                let pieces = read_u8(data, at)?;
+                for side in 0..sides {
+                    self.items[side][file].pieces[piece] =
+                        if side == 0 { pieces & 0xF } else { pieces >> 4 };
+                }
+                at += 1;
+            }
+
+            for (items, order) in self.items.iter_mut().zip(order).take(sides) {
+                items[file].set_groups(material, order, file);
+            }
+        }
+        at += at & 1;
+
+        for file in 0..file_count {
+            for side in 0..sides {
+                at = self.items[side][file].set_sizes(data, at)?;
+            }
+        }
+
+        if self.kind == TableKind::Dtz {
+            self.map = at;
+            for file in 0..file_count {
+                let item = &mut self.items[0][file];
+                if item.flags & MAPPED == 0 {
+                    continue;
+                }
+                if item.flags & WIDE == 0 {
+                    for map_index in &mut item.map_index {
+                        *map_index = u16::try_from(at - self.map + 1).ok()?;
+                        at += usize::from(read_u8(data, at)?) + 1;
+                    }
+                } else {
+                    at += at & 1;
+                    for map_index in &mut item.map_index {
+                        *map_index = u16::try_from((at - self.map) / 2 + 1).ok()?;
+                        at += usize::from(read_u16_le(data, at)?) * 2 + 2;
+                    }
+                }
+            }
+            at += at & 1;
+        }
+
+        for file in 0..file_count {
+            for side in 0..sides {
+                let item = &mut self.items[side][file];
+                item.sparse_index = at;
+                at += item.sparse_index_size * 6;
+            }
+        }
+        for file in 0..file_count {
+            for side in 0..sides {
+                let item = &mut self.items[side][file];
+                item.block_length = at;
+                at += item.block_length_size * 2;
+            }
+        }
+        for file in 0..file_count {
+            for side in 0..sides {
+                let item = &mut self.items[side][file];
+                at = (at + 0x3F) & !0x3F;
+                item.data = at;
+                at = at.checked_add(item.block_count.checked_mul(item.block_size)?)?;
+            }
+        }
+
+        // The blocks are read as zero past the end, so a truncated file is only caught here
+        (at <= data.len()).then_some(())
+    }
+
+    /// Returns the subtable for the side to move and leading pawn file.
+    ///
+    /// Distance to zeroing tables only have one side.
+    const fn item(&self, side_to_move: usize, file: usize) -> &PairsData {
+        match self.kind {
+            TableKind::Wdl => &self.items[side_to_move][file],
+            TableKind::Dtz => &self.items[0][file],
+        }
+    }
+
+    /// Converts a decompressed value into a score, or returns `None` if the map is corrupt.
+    fn map_score(&self, file: usize, value: usize, wdl: i32) -> Option<i32> {
+        match self.kind {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            TableKind::Wdl => Some(value as i32 - 2),
+            TableKind::Dtz => {
+                const WDL_MAP: [usize; 5] = [1, 3, 0, 2, 0];
+
+                let item = self.item(0, file);
+                let mut value = value;
+                if item.flags & MAPPED != 0 {
+                    #[allow(clippy::cast_sign_loss)]
+                    let index = usize::from(item.map_index[WDL_MAP[(wdl + 2) as usize]]) + value;
+                    value = if item.flags & WIDE == 0 {
+                        usize::from(read_u8(&self.data, self.map + index)?)
+                    } else {
+                        usize::from(read_u16_le(&self.data, self.map + index * 2)?)
+                    };
+                }
+
+                // Convert moves into plies
+                if (wdl == 2 && item.flags & WIN_PLIES == 0)
+                    || (wdl == -2 && item.flags & LOSS_PLIES == 0)
+                    || wdl == 1
+                    || wdl == -1
+                {
+                    value *= 2;
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                let value = value as i32;
+                Some(value + 1)
+            }
+        }
+    }
+
+    /// Looks up the position, where `position_key` is its material key.
+    ///
+    /// `wdl` is the win/draw/loss of the position, used to decode distance to zeroing.
+    /// Returns `None` if the table is corrupt.
+    #[allow(clippy::too_many_lines)]
+    pub fn probe(
+        &self,
+        board: &Board,
+        material: &Material,
+        position_key: u64,
+        wdl: i32,
+    ) -> Option<Probe> {
+        // Pieces numbered like in the table files, with black pieces 8 higher
+        let mut piece_at = [0u8; 64];
+        for (index, bit_board) in board.bit_boards.iter().enumerate() {
+            let mut bit_board = *bit_board;
+            #[allow(clippy::cast_possible_truncation)]
+            let piece = if index < 6 { index + 1 } else { index + 3 } as u8;
+            while bit_board.is_not_empty() {
+                piece_at[bit_board.pop_square().usize()] = piece;
+            }
+        }
+
+        // Tables are stored with white as the stronger side, and symmetric tables only with
+        // white to move, otherwise the colours are swapped and the board flipped
+        let symmetric_black_to_move = material.is_symmetric() && !board.white_to_move;
+        let black_stronger = position_key != material.key;
+        let flipped = symmetric_black_to_move || black_stronger;
+        let flip_colour = if flipped { 8 } else { 0 };
+        let flip_squares = if flipped { 56 } else { 0 };
+        let side_to_move = usize::from(flipped) ^ usize::from(!board.white_to_move);
+
+        let mut squares = [0usize; MAX_PIECES];
+        let mut pieces = [0u8; MAX_PIECES];
+        let mut size = 0;
+        let mut lead_pawn = 0;
+        let mut lead_pawn_count = 0;
+        let mut file = 0;
+
+        if material.has_pawns {
+            // The leading pawns are the colour of the first piece
+            lead_pawn = self.item(0, 0).pieces[0] ^ flip_colour;
+            for (square, &piece) in piece_at.iter().enumerate() {
+                if piece == lead_pawn {
+                    squares[size] = square ^ flip_squares;
+                    pieces[size] = lead_pawn ^ flip_colour;
+                    size += 1;
+                }
+            }
+            lead_pawn_count = size;
+
+            let leading = (0..lead_pawn_count).max_by_key(|&index| MAP_PAWNS[squares[index]])?;
+            squares.swap(0, leading);
+
+            let leading_file = squares[0] & 7;
+            file = leading_file.min(7 - leading_file);
+        }
+
+        if self.kind == TableKind::Dtz {
+            let flags = self.item(side_to_move, file).flags;
+            if usize::from(flags & SIDE_TO_MOVE) != side_to_move
+                && (!material.is_symmetric() || material.has_pawns)
+            {
+                return Some(Probe::OtherSideToMove);
+            }
+        }
+
+        for (square, &piece) in piece_at.iter().enumerate() {
+            if piece != 0 && piece != lead_pawn {
+                squares[size] = square ^ flip_squares;
+                pieces[size] = piece ^ flip_colour;
+                size += 1;
+            }
+        }
+
+        let item = self.item(side_to_move, file);
+
+        // Order the pieces like in the table
+        for index in lead_pawn_count..size - 1 {
+            for other in index + 1..size {
+                if item.pieces[index] == pieces[other] {
+                    pieces.swap(index, other);
+                    squares.swap(index, other);
+                    break;
+                }
+            }
+        }
+
+        // The leading piece goes on the a-d files
+        if squares[0] & 7 > 3 {
+            for square in &mut squares[..size] {
+                *square ^= 7;
+            }
+        }
+
+        let mut index = if material.has_pawns {
+            squares[1..lead_pawn_count].sort_by_key(|&square| MAP_PAWNS[square]);
+            let mut index = LEAD_PAWNS.0.get(lead_pawn_count)?[squares[0]];
+            for (pawn, &square) in squares.iter().enumerate().take(lead_pawn_count).skip(1) {
+                index += BINOMIAL[pawn][MAP_PAWNS[square]];
+            }
+            index
+        } else {
+            // The leading piece goes on ranks 1-4
+            if squares[0] >> 3 > 3 {
+                for square in &mut squares[..size] {
+                    *square ^= 0b111_000;
+                }
+            }
+
+            // The first leading group piece not on the diagonal goes below it
+            for first in 0..item.group_length[0] {
+                let off = off_diagonal(squares[first]);
+                if off == 0 {
+                    continue;
+                }
+                if off > 0 {
+                    for square in &mut squares[first..size] {
+                        *square = ((*square >> 3) | (*square << 3)) & 63;
+                    }
+                }
+                break;
+            }
+
+            if material.has_unique_pieces {
+                let [first, second, third] = [squares[0], squares[1], squares[2]].map(|s| s as u64);
+                let adjust_second = u64::from(second > first);
+                let adjust_third = u64::from(third > first) + u64::from(third > second);
+
+                if off_diagonal(squares[0]) != 0 {
+                    (MAP_A1D1D4[squares[0]] * 63 + (second - adjust_second)) * 62 + third
+                        - adjust_third
+                } else if off_diagonal(squares[1]) != 0 {
+                    (6 * 63 + (first >> 3) * 28 + MAP_B1H1H7[squares[1]]) * 62 + third
+                        - adjust_third
+                } else if off_diagonal(squares[2]) != 0 {
+                    6 * 63 * 62
+                        + 4 * 28 * 62
+                        + (first >> 3) * 7 * 28
+                        + ((second >> 3) - adjust_second) * 28
+                        + MAP_B1H1H7[squares[2]]
+                } else {
+                    6 * 63 * 62
+                        + 4 * 28 * 62
+                        + 4 * 7 * 28
+                        + (first >> 3) * 7 * 6
+                        + ((second >> 3) - adjust_second) * 6
+                        + ((third >> 3) - adjust_third)
+                }
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let king_index = MAP_A1D1D4[squares[0]] as usize;
+                MAP_KK[king_index][squares[1]]
+            }
+        };
+
+        index *= item.group_index[0];
+
+        // Each remaining group is indexed by its squares in ascending order, skipping squares
+        // taken by earlier groups
+        let mut group_start = item.group_length[0];
+        let mut remaining_pawns = material.has_pawns && material.pawn_count[1] != 0;
+        let mut group = 1;
+        while item.group_length[group] != 0 {
+            let group_end = group_start + item.group_length[group];
+            squares[group_start..group_end].sort_unstable();
+
+            let mut group_index = 0;
+            for (nth, &square) in squares[group_start..group_end].iter().enumerate() {
+                let adjust = squares[..group_start]
+                    .iter()
+                    .filter(|&&earlier| square > earlier)
+                    .count();
+                let free_square =
+                    square.checked_sub(adjust + if remaining_pawns { 8 } else { 0 })?;
+                group_index += BINOMIAL[nth + 1][free_square];
+            }
+
+            remaining_pawns = false;
+            index += group_index * item.group_index[group];
+            group_start = group_end;
+            group += 1;
+        }
+
+        let value = item.decompress(&self.data, index)?;
+        Some(Probe::Value(self.map_score(file, value, wdl)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{
+        BINOMIAL, DTZ_MAGIC, LEAD_PAWNS, MAP_A1D1D4, MAP_KK, MAP_PAWNS, Material, Probe, Table,
+        TableKind, WDL_MAGIC, WIN_PLIES,
+    };
+    use crate::{
+        board::Board,
+        move_generator::move_data::Move,
+        tablebase::{MAX_DTZ, Tablebase, Wdl, material_key},
+        uci::encode_move,
+    };
+
+    /// Positions in a table of two kings and one other piece.
+    const TABLE_SIZE: u64 = 31332;
+
+    const BLOCK_SIZE: usize = 64;
+    const SPAN: u64 = 1024;
+
+    /// Every symbol is 3 bits long, and is one value.
+    const VALUES_PER_BLOCK: u64 = (BLOCK_SIZE as u64 * 8) / 3;
+
+    fn king_queen_king() -> Material {
+        let white = [0, 0, 0, 0, 1, 1];
+        let black = [0, 0, 0, 0, 0, 1];
+        Material {
+            key: material_key(white, black),
+            mirrored_key: material_key(black, white),
+            piece_count: 3,
+            has_pawns: false,
+            has_unique_pieces: true,
+            pawn_count: [0, 0],
+        }
+    }
+
+    fn expected_value(side: usize, index: u64) -> u8 {
+        u8::try_from((index * 7 + side as u64 * 3) % 5).unwrap()
+    }
+
+    /// Builds a table of king and queen against king, compressed with a fixed length code,
+    /// with `value(side, index)` stored for each position.
+    fn compressed_table(kind: TableKind, flags: u8, value: impl Fn(usize, u64) -> u8) -> Vec<u8> {
+        let block_count = TABLE_SIZE.div_ceil(VALUES_PER_BLOCK);
+        let sparse_index_size = TABLE_SIZE.div_ceil(SPAN);
+
+        // Distance to zeroing tables only store one side to move
+        let (mut data, sides) = match kind {
+            TableKind::Wdl => (WDL_MAGIC.to_vec(), 2),
+            TableKind::Dtz => (DTZ_MAGIC.to_vec(), 1),
+        };
+        data.push(0);
+
+        // Order of the groups, then the king, queen and other king for both sides to move
+        data.extend([0x00, 0x66, 0x55, 0xEE, 0x00]);
+
+        for _ in 0..sides {
+            data.extend([flags, 6, 10, 0]);
+            data.extend(u32::try_from(block_count).unwrap().to_le_bytes());
+
+            // Symbols are between 3 and 3 bits long, and the lowest symbol of length 3 is 0
+            data.extend([3, 3, 0, 0]);
+            data.extend(5_u16.to_le_bytes());
+            for value in 0..5 {
+                data.extend([value, 0xF0, 0xFF]);
+            }
+            data.push(0);
+        }
+
+        for _ in 0..sides {
+            for entry in 0..sparse_index_size {
+                let index = entry * SPAN + SPAN / 2;
+                data.extend(
+                    u32::try_from(index / VALUES_PER_BLOCK)
+                        .unwrap()
+                        .to_le_bytes(),
+                );
+                data.extend(
+                    u16::try_from(index % VALUES_PER_BLOCK)
+                        .unwrap()
+                        .to_le_bytes(),
+                );
+            }
+        }
+        for _ in 0..sides {
+            for block in 0..block_count {
+                let values = VALUES_PER_BLOCK.min(TABLE_SIZE - block * VALUES_PER_BLOCK);
+                data.extend(u16::try_from(values - 1).unwrap().to_le_bytes());
+            }
+        }
+
+        for side in 0..sides {
+            data.resize(data.len().next_multiple_of(64), 0);
+            for block in 0..block_count {
+                let mut bits = vec![0; BLOCK_SIZE];
+                let first = block * VALUES_PER_BLOCK;
+                for index in first..TABLE_SIZE.min(first + VALUES_PER_BLOCK) {
+                    let bit = usize::try_from(index - first).unwrap() * 3;
+                    let value = value(side, index);
+                    for value_bit in 0..3 {
+                        if value & (0b100 >> value_bit) != 0 {
+                            bits[(bit + value_bit) / 8] |= 0x80 >> ((bit + value_bit) % 8);
+                        }
+                    }
+                }
+                data.extend(bits);
+            }
+        }
+        data
+    }
+
+    fn probe_all(table: &Table, material: &Material) -> Vec<Option<i32>> {
+        [
+            "7k/8/6K1/8/8/8/8/Q7 w - - 0 1",
+            "7k/8/6K1/8/8/8/8/Q7 b - - 0 1",
+            "3k4/8/8/8/4Q3/8/8/K7 w - - 0 1",
+            "q7/8/8/8/8/6k1/8/7K w - - 0 1",
+        ]
+        .into_iter()
+        .map(|fen| {
+            let board = Board::from_fen(fen).unwrap();
+            let (white, black) = super::super::piece_counts(&board);
+            match table.probe(&board, material, material_key(white, black), 0) {
+                Some(Probe::Value(value)) => Some(value),
+                Some(Probe::OtherSideToMove) => unreachable!(),
+                None => None,
+            }
+        })
+        .collect()
+    }
+
+    #[test]
+    fn compressed_values_are_decoded() {
+        let material = king_queen_king();
+        let data = compressed_table(TableKind::Wdl, 0, expected_value);
+        let table = Table::from_bytes(data, TableKind::Wdl, &material).unwrap();
+        for side in 0..2 {
+            for index in 0..TABLE_SIZE {
+                assert_eq!(
+                    table.items[side][0].decompress(&table.data, index),
+                    Some(usize::from(expected_value(side, index)))
+                );
+            }
+        }
+        assert!(
+            probe_all(&table, &material)
+                .iter()
+                .all(|value| value.is_some_and(|value| (-2..=2).contains(&value)))
+        );
+    }
+
+    #[test]
+    fn truncated_or_corrupt_tables_fail_without_panicking() {
+        let material = king_queen_king();
+        let data = compressed_table(TableKind::Wdl, 0, expected_value);
+
+        for length in 0..data.len() {
+            if let Some(table) =
+                Table::from_bytes(data[..length].to_vec(), TableKind::Wdl, &material)
+            {
+                let _ = probe_all(&table, &material);
+            }
+        }
+
+        // The header, sparse index and block lengths, and the start of the blocks
+        for at in 4..1600 {
+            for corrupt in [0x00, 0x7F, 0xFF] {
+                let mut data = data.clone();
+                data[at] = corrupt;
+                if let Some(table) = Table::from_bytes(data, TableKind::Wdl, &material) {
+                    let _ = probe_all(&table, &material);
+                }
+            }
+        }
+
+        assert!(
+            Table::from_bytes(data[..data.len() - 1].to_vec(), TableKind::Wdl, &material).is_none()
+        );
+    }
+
+    /// Returns a tablebase with only the king and queen against king tables, made from `wdl`
+    /// and `dtz`.
+    fn king_queen_king_tablebase(wdl: Vec<u8>, dtz: Vec<u8>) -> Tablebase {
+        let mut tablebase = Tablebase::default();
+        tablebase.add(PathBuf::from("KQvK.rtbw"));
+        let entry = &tablebase.tables[0];
+        assert!(
+            entry
+                .wdl
+                .set(Table::from_bytes(wdl, TableKind::Wdl, &entry.material))
+                .is_ok()
+        );
+        assert!(
+            entry
+                .dtz
+                .set(Table::from_bytes(dtz, TableKind::Dtz, &entry.material))
+                .is_ok()
+        );
+        tablebase
+    }
+
+    #[test]
+    fn root_moves_are_ranked_by_distance_to_zeroing() {
+        // The side with the queen always wins, in 5 plies when it is to move
+        let tablebase = king_queen_king_tablebase(
+            compressed_table(TableKind::Wdl, 0, |side, _| if side == 0 { 4 } else { 0 }),
+            compressed_table(TableKind::Dtz, WIN_PLIES, |_, _| 4),
+        );
+        let probe = |fen| {
+            let mut board = Board::from_fen(fen).unwrap();
+            (
+                tablebase.probe_wdl(&mut board).unwrap(),
+                tablebase.probe_dtz(&mut board).unwrap(),
+            )
+        };
+
+        assert_eq!(probe("3k4/8/8/8/4Q3/8/8/K7 w - - 0 1"), (Wdl::Win, 5));
+        assert_eq!(probe("k7/8/8/8/4q3/8/8/3K4 b - - 0 1"), (Wdl::Win, 5));
+
+        // The other side to move is looked up through its moves, one ply further
+        assert_eq!(probe("3k4/8/8/8/4Q3/8/8/K7 b - - 0 1"), (Wdl::Loss, -6));
+
+        // Taking the queen is searched instead of looked up
+        assert_eq!(probe("8/8/8/3k4/4Q3/8/8/K7 b - - 0 1"), (Wdl::Draw, 0));
+
+        let rank_root_moves = |fen| {
+            let mut board = Board::from_fen(fen).unwrap();
+            tablebase.rank_root_moves(&mut board).unwrap()
+        };
+        let rank = |ranked: &[(Move, i32)], uci: &str| {
+            ranked
+                .iter()
+                .find(|&&(move_data, _)| encode_move(move_data) == uci)
+                .map(|&(_, rank)| rank)
+                .unwrap()
+        };
+
+        // Every move keeps the win, except for giving away the queen
+        let ranked = rank_root_moves("8/8/8/4k3/8/8/8/K2Q4 w - - 0 1");
+        assert_eq!(rank(&ranked, "d1d5"), 0);
+        assert_eq!(rank(&ranked, "a1b1"), MAX_DTZ);
+        assert!(ranked.iter().all(|&(_, rank)| rank == 0 || rank == MAX_DTZ));
+
+        // Too close to the fifty-move rule, so the win is ranked lower
+        let ranked = rank_root_moves("8/8/8/4k3/8/8/8/K2Q4 w - - 97 1");
+        assert_eq!(rank(&ranked, "d1d5"), 0);
+        assert_eq!(rank(&ranked, "a1b1"), MAX_DTZ - (7 + 97));
+    }
+
+    #[test]
+    fn index_tables_are_correct() {
+        assert_eq!(BINOMIAL[2][62], 62 * 61 / 2);
+        assert_eq!(BINOMIAL[0][10], 1);
+
+        // a1, b2, c3 and d4 go after the squares below the diagonal
+        assert_eq!(MAP_A1D1D4[1], 0);
+        assert_eq!(MAP_A1D1D4[19], 5);
+        assert_eq!(MAP_A1D1D4[0], 6);
+        assert_eq!(MAP_A1D1D4[27], 9);
+
+        assert_eq!(MAP_KK.iter().flatten().max(), Some(&461));
+
+        // a2 is the leading pawn square with the most available squares
+        assert_eq!(MAP_PAWNS[8], 47);
+        assert_eq!(MAP_PAWNS[15], 46);
+        assert_eq!(LEAD_PAWNS.1[1], [6; 4]);
+    }
+}
@@ -46,3 +46,15 @@ mod inner {
 }
 
 pub use inner::Time;
+
+/// Measures milliseconds passed, so that time can be faked in tests.
+pub trait Clock: Sync {
+    /// Returns milliseconds since the clock started.
+    fn milliseconds(&self) -> u64;
+}
+
+impl Clock for Time {
+    fn milliseconds(&self) -> u64 {
+        Self::milliseconds(self)
+    }
+}
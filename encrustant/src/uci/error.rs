@@ -0,0 +1,80 @@
+use core::fmt;
+
+use crate::board::fen::FenParseErr;
+
+/// Errors from UCI input that could not be handled.
+#[derive(Debug)]
+pub enum UciError {
+    /// The command is not known.
+    UnknownCommand(String),
+
+    /// `setoption` was sent without `name`.
+    MissingOptionName,
+
+    /// `setoption` was sent with `value` before `name`.
+    ValueBeforeName,
+
+    /// There is no option with this name.
+    UnknownOption(String),
+
+    /// An option or parameter needs a value, but none was given.
+    MissingValue(String),
+
+    /// The value of an option or parameter could not be read, or is out of range.
+    InvalidValue {
+        /// Name of the option or parameter.
+        name: String,
+
+        /// The value that was given.
+        value: String,
+    },
+
+    /// A parameter of `go` was given more than once.
+    RepeatedParameter(String),
+
+    /// A parameter of `go` is not known.
+    UnknownParameter(String),
+
+    /// `perft` was given together with search parameters.
+    ConflictingSearchType,
+
+    /// The FEN of `position` could not be parsed.
+    InvalidFen(FenParseErr),
+
+    /// The FEN of `position` was parsed, but is not a position that can be searched.
+    IllegalPosition(&'static str),
+
+    /// `go` was sent before a position was set.
+    MissingPosition,
+
+    /// A move is not in UCI notation.
+    InvalidMove(String),
+
+    /// A move is not legal in its position.
+    IllegalMove(String),
+}
+
+impl fmt::Display for UciError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(command) => write!(f, "Unknown command {command}"),
+            Self::MissingOptionName => write!(f, "Missing option name"),
+            Self::ValueBeforeName => write!(f, "Option value given before its name"),
+            Self::UnknownOption(name) => write!(f, "Unknown option {name}"),
+            Self::MissingValue(name) => write!(f, "Missing value for {name}"),
+            Self::InvalidValue { name, value } => write!(f, "Invalid value {value} for {name}"),
+            Self::RepeatedParameter(name) => write!(f, "Parameter {name} given more than once"),
+            Self::UnknownParameter(name) => write!(f, "Unknown parameter {name}"),
+            Self::ConflictingSearchType => {
+                write!(f, "perft can not be combined with search parameters")
+            }
+            Self::InvalidFen(error) => write!(f, "Invalid FEN ({error:?})"),
+            Self::IllegalPosition(reason) => write!(f, "Illegal position ({reason})"),
+            Self::MissingPosition => write!(f, "No position was set"),
+            Self::InvalidMove(uci_move) => write!(f, "Invalid move notation {uci_move}"),
+            Self::IllegalMove(uci_move) => write!(f, "Illegal move {uci_move}"),
+        }
+    }
+}
+
+impl core::error::Error for UciError {}
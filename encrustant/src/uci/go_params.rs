@@ -1,8 +1,12 @@
 use core::num::{NonZeroU16, NonZeroU64};
 use core::str::SplitWhitespace;
 
+use crate::board::{Board, square::Square};
+use crate::move_generator::move_data::{Flag, Move};
 use crate::search::Ply;
 
+use super::{UciError, decode_legal_move, encode_move, parse_move};
+
 #[derive(Default)]
 pub struct SearchTime {
     pondering: Option<bool>,
@@ -22,6 +26,8 @@ pub struct SearchTime {
     white_increment: Option<NonZeroU64>,
     black_increment: Option<NonZeroU64>,
     moves_to_go: Option<NonZeroU16>,
+
+    search_moves: Vec<(Square, Square, Flag)>,
 }
 
 impl SearchTime {
@@ -75,6 +81,44 @@ impl SearchTime {
     pub const fn black_increment(&self) -> Option<NonZeroU64> {
         self.black_increment
     }
+
+    /// Moves until the next time control, if the clock is not sudden death.
+    #[must_use]
+    pub const fn moves_to_go(&self) -> Option<NonZeroU16> {
+        self.moves_to_go
+    }
+
+    /// Reads moves in uci notation until the next parameter.
+    fn parse_search_moves(&mut self, args: &mut SplitWhitespace) -> Result<(), UciError> {
+        while let Some(uci_move) = args.clone().next()
+            && uci_move.len() >= 4
+            && uci_move.as_bytes()[1].is_ascii_digit()
+        {
+            self.search_moves.push(parse_move(uci_move)?);
+            args.next();
+        }
+        Ok(())
+    }
+
+    /// Moves the root is limited to, or empty to search every move.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if any of them is not legal in `board`.
+    pub fn search_moves(&self, board: &Board) -> Result<Vec<Move>, UciError> {
+        self.search_moves
+            .iter()
+            .map(|&(from, to, promotion)| {
+                decode_legal_move(board, from, to, promotion).ok_or_else(|| {
+                    UciError::IllegalMove(encode_move(Move {
+                        from,
+                        to,
+                        flag: promotion,
+                    }))
+                })
+            })
+            .collect()
+    }
 }
 
 pub enum SearchType {
@@ -93,27 +137,39 @@ impl GoParameters {
         Self { search_type: None }
     }
 
-    pub fn parse(&mut self, args: &mut SplitWhitespace) {
+    /// Reads the parameters of `go`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a parameter is unknown, repeated, or has an invalid value.
+    pub fn parse(&mut self, args: &mut SplitWhitespace) -> Result<(), UciError> {
         while let Some(label) = args.next() {
-            macro_rules! parse_number {
+            let repeated = || Err(UciError::RepeatedParameter(label.to_owned()));
+            macro_rules! next_value {
                 () => {
-                    args.next().unwrap().parse()
+                    args.next()
+                        .ok_or_else(|| UciError::MissingValue(label.to_owned()))
                 };
             }
+            macro_rules! parse_number {
+                () => {{
+                    let value = next_value!()?;
+                    value.parse().map_err(|_| UciError::InvalidValue {
+                        name: label.to_owned(),
+                        value: value.to_owned(),
+                    })
+                }};
+            }
             match label {
                 "wtime" | "btime" | "winc" | "binc" | "ponder" | "movetime" | "depth" | "nodes"
-                | "infinite" | "movestogo" | "mate" => {
+                | "infinite" | "movestogo" | "mate" | "searchmoves" => {
                     match self.search_type {
                         None => {
                             self.search_type = Some(SearchType::Normal(SearchTime::default()));
                         }
                         Some(SearchType::Normal(_)) => {}
-                        Some(SearchType::Perft(_)) => {
-                            panic!(
-                                "search parameters specified when search type already determined"
-                            )
-                        }
-                    };
+                        Some(SearchType::Perft(_)) => return Err(UciError::ConflictingSearchType),
+                    }
                     let search_time = match self.search_type {
                         Some(SearchType::Normal(ref mut search_time)) => search_time,
                         _ => unreachable!(),
@@ -121,84 +177,160 @@ impl GoParameters {
 
                     match label {
                         "wtime" => {
-                            assert!(search_time.white_time.is_none(), "Overwritten wtime");
-                            search_time.white_time = Some(parse_number!().unwrap());
+                            if search_time.white_time.is_some() {
+                                return repeated();
+                            }
+                            // Some GUIs send a negative time once the clock has run out
+                            let time: i64 = parse_number!()?;
+                            search_time.white_time = Some(time.max(0).unsigned_abs());
                         }
                         "btime" => {
-                            assert!(search_time.black_time.is_none(), "Overwritten btime");
-                            search_time.black_time = Some(parse_number!().unwrap());
+                            if search_time.black_time.is_some() {
+                                return repeated();
+                            }
+                            let time: i64 = parse_number!()?;
+                            search_time.black_time = Some(time.max(0).unsigned_abs());
                         }
 
                         "winc" => {
-                            assert!(search_time.white_increment.is_none(), "Overwritten winc");
-                            if let Ok(winc) = parse_number!() {
-                                search_time.white_increment = NonZeroU64::new(winc);
-                            } else {
-                                search_time.white_increment = None;
+                            if search_time.white_increment.is_some() {
+                                return repeated();
                             }
+                            search_time.white_increment =
+                                NonZeroU64::new(next_value!()?.parse().unwrap_or(0));
                         }
                         "binc" => {
-                            assert!(search_time.black_increment.is_none(), "Overwritten binc");
-                            if let Ok(binc) = parse_number!() {
-                                search_time.black_increment = NonZeroU64::new(binc);
-                            } else {
-                                search_time.black_increment = None;
+                            if search_time.black_increment.is_some() {
+                                return repeated();
                             }
+                            search_time.black_increment =
+                                NonZeroU64::new(next_value!()?.parse().unwrap_or(0));
                         }
                         "ponder" => {
-                            assert!(search_time.pondering.is_none(), "Pondering defined twice");
+                            if search_time.pondering.is_some() {
+                                return repeated();
+                            }
                             search_time.pondering = Some(true);
                         }
                         "movetime" => {
-                            assert!(search_time.fixed_time.is_none(), "Overwritten movetime");
-                            search_time.fixed_time = Some(parse_number!().unwrap());
+                            if search_time.fixed_time.is_some() {
+                                return repeated();
+                            }
+                            search_time.fixed_time = Some(parse_number!()?);
                         }
                         "depth" => {
-                            assert!(search_time.depth.is_none(), "Overwritten depth");
-                            search_time.depth = Some(parse_number!().unwrap());
+                            if search_time.depth.is_some() {
+                                return repeated();
+                            }
+                            search_time.depth = Some(parse_number!()?);
                         }
                         "nodes" => {
-                            assert!(search_time.nodes.is_none(), "Overwritten nodes");
-                            search_time.nodes = Some(parse_number!().unwrap());
+                            if search_time.nodes.is_some() {
+                                return repeated();
+                            }
+                            search_time.nodes = Some(parse_number!()?);
                         }
                         "infinite" => {
-                            assert!(!search_time.infinite, "infinite specified twice");
+                            if search_time.infinite {
+                                return repeated();
+                            }
                             search_time.infinite = true;
                         }
                         "movestogo" => {
-                            assert!(search_time.moves_to_go.is_none(), "Overwritten movestogo");
-                            search_time.moves_to_go = Some(parse_number!().unwrap());
+                            if search_time.moves_to_go.is_some() {
+                                return repeated();
+                            }
+                            search_time.moves_to_go = Some(parse_number!()?);
                         }
                         "mate" => {
-                            assert!(search_time.mate_in_moves.is_none(), "Overwritten mate");
-                            let mate_in_moves = parse_number!().unwrap();
+                            if search_time.mate_in_moves.is_some() {
+                                return repeated();
+                            }
+                            let mate_in_moves: Ply = parse_number!()?;
                             const MAX_MOVES: u32 = (Ply::MAX as u32 + 1) / 2;
-                            assert!(mate_in_moves < MAX_MOVES as Ply);
+                            if mate_in_moves == 0 || u32::from(mate_in_moves) >= MAX_MOVES {
+                                return Err(UciError::InvalidValue {
+                                    name: label.to_owned(),
+                                    value: mate_in_moves.to_string(),
+                                });
+                            }
                             search_time.mate_in_moves = Some(mate_in_moves);
                         }
+                        "searchmoves" => {
+                            if !search_time.search_moves.is_empty() {
+                                return repeated();
+                            }
+                            search_time.parse_search_moves(args)?;
+                        }
                         _ => unreachable!(),
                     }
                 }
 
                 "perft" => {
-                    assert!(
-                        matches!(self.search_type, None | Some(SearchType::Normal(_))),
-                        "perft specified when search type already determined"
-                    );
-                    self.search_type = Some(SearchType::Perft(
-                        parse_number!().expect("Perft depth not specified"),
-                    ));
+                    match self.search_type {
+                        None => {}
+                        Some(SearchType::Normal(_)) => return Err(UciError::ConflictingSearchType),
+                        Some(SearchType::Perft(_)) => return repeated(),
+                    }
+                    self.search_type = Some(SearchType::Perft(parse_number!()?));
                 }
 
-                "searchmoves" => todo!(),
-
-                _ => panic!("Unknown parameter"),
+                _ => return Err(UciError::UnknownParameter(label.to_owned())),
             }
         }
+        Ok(())
     }
 
     #[must_use]
-    pub const fn search_type(self) -> Option<SearchType> {
+    pub fn search_type(self) -> Option<SearchType> {
         self.search_type
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::Board, uci::go_params::SearchType};
+
+    use super::GoParameters;
+
+    fn parse(parameters: &str) -> GoParameters {
+        let mut go_parameters = GoParameters::empty();
+        go_parameters
+            .parse(&mut parameters.split_whitespace())
+            .unwrap();
+        go_parameters
+    }
+
+    #[test]
+    fn search_moves_are_parsed() {
+        let board = Board::from_fen(Board::START_POSITION_FEN).unwrap();
+
+        let Some(SearchType::Normal(search_time)) =
+            parse("searchmoves e2e4 g1f3 depth 5").search_type()
+        else {
+            panic!("Not a normal search")
+        };
+        assert_eq!(search_time.depth(), Some(5));
+        assert_eq!(search_time.search_moves(&board).unwrap().len(), 2);
+
+        let Some(SearchType::Normal(search_time)) = parse("depth 5").search_type() else {
+            panic!("Not a normal search")
+        };
+        assert!(search_time.search_moves(&board).unwrap().is_empty());
+
+        let Some(SearchType::Normal(search_time)) = parse("searchmoves e2e5").search_type() else {
+            panic!("Not a normal search")
+        };
+        assert!(search_time.search_moves(&board).is_err());
+    }
+
+    #[test]
+    fn negative_clock_is_out_of_time() {
+        let Some(SearchType::Normal(search_time)) = parse("wtime -15 btime 300").search_type()
+        else {
+            panic!("Not a normal search")
+        };
+        assert_eq!(search_time.white_time(), Some(0));
+        assert_eq!(search_time.black_time(), Some(300));
+    }
+}
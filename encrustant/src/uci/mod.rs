@@ -1,26 +1,31 @@
 //! Universal Chess Protocol
 
-use core::ops::{Range, RangeInclusive};
-use core::str::SplitWhitespace;
+use core::ops::{Range, RangeBounds, RangeInclusive};
+use core::str::{FromStr, SplitWhitespace};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+mod error;
 mod go_params;
 mod move_encoding;
 mod search_controller;
 
-use go_params::SearchType;
-pub use move_encoding::{decode_move, encode_move};
+pub use error::UciError;
+use go_params::{SearchTime, SearchType};
+pub use move_encoding::{decode_legal_move, decode_move, encode_move, parse_move};
 use search_controller::SearchController;
 
 use crate::{
-    board::{Board, square::Square},
-    move_generator::move_data::Flag,
+    board::{Board, piece::Piece, square::Square},
+    book::{Book, BookSelection},
+    evaluation::nnue::Network,
+    move_generator::{MoveGenerator, move_data::Flag},
     perft::perft_root,
     search::{
         search_params::{DEFAULT_TUNABLES, Tunable},
         transposition::megabytes_to_capacity,
     },
+    tablebase::Tablebase,
     timer::Time,
 };
 
@@ -40,12 +45,53 @@ impl SpinU16 {
     }
 }
 
+/// Reads the value of the option `name`, which must be within `range`.
+fn option_value<T: FromStr + PartialOrd>(
+    name: &str,
+    value: Option<&str>,
+    range: impl RangeBounds<T>,
+) -> Result<T, UciError> {
+    let value = value.ok_or_else(|| UciError::MissingValue(name.to_owned()))?;
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|parsed_value| range.contains(parsed_value))
+        .ok_or_else(|| UciError::InvalidValue {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        })
+}
+
 #[cfg(target_arch = "wasm32")]
 type Bool = bool;
 
 #[cfg(not(target_arch = "wasm32"))]
 type Bool = Arc<AtomicBool>;
 
+/// Most search threads that can be used.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_THREADS: u16 = 1024;
+
+/// Threads are not available, so only the main search thread can be used.
+#[cfg(target_arch = "wasm32")]
+const MAX_THREADS: u16 = 1;
+
+/// Most lines that can be searched with `MultiPV`.
+const MAX_MULTI_PV: u16 = 256;
+
+/// Milliseconds kept in reserve for lag by default.
+const DEFAULT_MOVE_OVERHEAD: u16 = 10;
+
+/// Most milliseconds that can be kept in reserve for lag.
+const MAX_MOVE_OVERHEAD: u16 = 5000;
+
+/// Most pieces in a position that the tablebase is probed with by default.
+const DEFAULT_SYZYGY_PROBE_LIMIT: u8 = 7;
+
+/// How many moves into the game the opening book is used for by default.
+const DEFAULT_BOOK_DEPTH: u16 = 20;
+
 /// Handles UCI input and output.
 pub struct UCIProcessor {
     /// FEN to be used.
@@ -63,6 +109,39 @@ pub struct UCIProcessor {
     /// Maximum entry count of the transposition table.
     transposition_capacity: usize,
 
+    /// How many threads search at once.
+    threads: u16,
+
+    /// How many of the best lines are searched and output.
+    multi_pv: u16,
+
+    /// Milliseconds kept in reserve for lag between the engine and the GUI.
+    move_overhead: u16,
+
+    /// Syzygy tablebase, if a path to one was given.
+    tablebase: Option<Arc<Tablebase>>,
+
+    /// Most pieces in a position that the tablebase is probed with.
+    syzygy_probe_limit: u8,
+
+    /// Network to evaluate with, if a file for one was given.
+    network: Option<Arc<Network>>,
+
+    /// Whether moves are played from the opening book.
+    own_book: bool,
+
+    /// Opening book, if a file for one was given.
+    book: Option<Arc<Book>>,
+
+    /// How many moves into the game the opening book is used for.
+    book_depth: u16,
+
+    /// Whether the book move with the highest weight is always played.
+    best_book_move: bool,
+
+    /// Whether statistics are output after each search, set with `debug`.
+    debug: bool,
+
     stopped: Bool,
 
     ponder_info: PonderInfo,
@@ -94,11 +173,20 @@ struct TunableRange {
     //pub lmr_min_depth: RangeInclusive<u8>,
     pub lmr_ply_multiplier: RangeInclusive<u32>,
     pub lmr_index_multiplier: RangeInclusive<u32>,
+    pub lmr_history_divisor: RangeInclusive<i32>,
 
     //pub lmp_base: RangeInclusive<u32>,
+    pub see_capture_margin: RangeInclusive<i32>,
     //pub nmp_min_depth: RangeInclusive<u8>,
     //pub nmp_base_reduction: RangeInclusive<u8>,
     //pub nmp_ply_divisor: RangeInclusive<u8>,
+    //pub razoring_max_depth: RangeInclusive<u8>,
+    pub razoring_margin: RangeInclusive<i32>,
+    //pub probcut_min_depth: RangeInclusive<u8>,
+    //pub probcut_depth_reduction: RangeInclusive<u8>,
+    pub probcut_margin: RangeInclusive<i32>,
+    //pub singular_min_depth: RangeInclusive<u8>,
+    pub singular_margin: RangeInclusive<i32>,
     pub aspiration_window_start: RangeInclusive<i32>,
     pub aspiration_window_growth: RangeInclusive<i32>,
     pub aspiration_window_count: RangeInclusive<u32>,
@@ -124,10 +212,19 @@ const TUNABLE_RANGES: TunableRange = TunableRange {
     //lmr_min_depth: 1..=5,
     lmr_ply_multiplier: 70..=200,
     lmr_index_multiplier: 70..=200,
+    lmr_history_divisor: 4..=64,
     //lmp_base: 2..=5,
+    see_capture_margin: 40..=200,
     //nmp_min_depth: 1..=5,
     //nmp_base_reduction: 1..=6,
     //nmp_ply_divisor: 4..=9,
+    //razoring_max_depth: 1..=5,
+    razoring_margin: 150..=500,
+    //probcut_min_depth: 3..=8,
+    //probcut_depth_reduction: 3..=5,
+    probcut_margin: 100..=350,
+    //singular_min_depth: 4..=10,
+    singular_margin: 1..=6,
     aspiration_window_start: 10..=60,
     aspiration_window_growth: 20..=90,
     aspiration_window_count: 2..=8,
@@ -166,6 +263,17 @@ impl UCIProcessor {
                 is_pondering: false,
             },
             transposition_capacity,
+            threads: 1,
+            multi_pv: 1,
+            move_overhead: DEFAULT_MOVE_OVERHEAD,
+            tablebase: None,
+            syzygy_probe_limit: DEFAULT_SYZYGY_PROBE_LIMIT,
+            network: None,
+            own_book: false,
+            book: None,
+            book_depth: DEFAULT_BOOK_DEPTH,
+            best_book_move: false,
+            debug: false,
             search_controller: None,
             #[cfg(feature = "spsa")]
             tunables: DEFAULT_TUNABLES,
@@ -177,18 +285,126 @@ impl UCIProcessor {
             search_controller.set_transposition_capacity(transposition_capacity);
         }
     }
+    fn set_threads(&mut self, threads: u16) {
+        self.threads = threads;
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_threads(threads.into());
+        }
+    }
+    fn set_multi_pv(&mut self, multi_pv: u16) {
+        self.multi_pv = multi_pv;
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_multi_pv(multi_pv.into());
+        }
+    }
+    fn set_move_overhead(&mut self, move_overhead: u16) {
+        self.move_overhead = move_overhead;
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_move_overhead(move_overhead.into());
+        }
+    }
+    fn set_debug(&mut self, debug: bool) {
+        self.debug = debug;
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_debug(debug);
+        }
+    }
+    fn update_tablebase(&mut self) {
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_tablebase(self.tablebase.clone(), self.syzygy_probe_limit.into());
+        }
+    }
+    fn set_syzygy_path(&mut self, path: &str) {
+        self.tablebase = if path.is_empty() || path == "<empty>" {
+            None
+        } else {
+            let tablebase = Tablebase::new(path);
+            (self.out)(&format!(
+                "info string Found {} tablebase files with up to {} pieces",
+                tablebase.table_count(),
+                tablebase.max_pieces()
+            ));
+            Some(Arc::new(tablebase))
+        };
+        self.update_tablebase();
+    }
+    fn set_eval_file(&mut self, path: &str) {
+        self.network = if path.is_empty() || path == "<empty>" {
+            None
+        } else {
+            match Network::load(path) {
+                Ok(network) => {
+                    (self.out)("info string Using network for evaluation");
+                    Some(Arc::new(network))
+                }
+                Err(error) => {
+                    (self.out)(&format!("info string Could not read network: {error}"));
+                    None
+                }
+            }
+        };
+        self.update_network();
+    }
+    fn update_network(&mut self) {
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_network(self.network.clone());
+        }
+    }
+    fn set_book_file(&mut self, path: &str) {
+        self.book = if path.is_empty() || path == "<empty>" {
+            None
+        } else {
+            match Book::load(path) {
+                Ok(book) => {
+                    (self.out)(&format!("info string Found {} book entries", book.len()));
+                    Some(Arc::new(book))
+                }
+                Err(error) => {
+                    (self.out)(&format!("info string Could not read book: {error}"));
+                    None
+                }
+            }
+        };
+        self.update_book();
+    }
+    fn update_book(&mut self) {
+        if let Some(search_controller) = &mut self.search_controller {
+            search_controller.set_book(
+                self.book.clone().filter(|_| self.own_book),
+                self.book_depth,
+                if self.best_book_move {
+                    BookSelection::Best
+                } else {
+                    BookSelection::Weighted
+                },
+            );
+        }
+    }
 }
 
 impl UCIProcessor {
     /// Outputs `id` command, `option` commands, and `uciok`
-    pub fn uci(&self) {
+    ///
+    /// # Errors
+    ///
+    /// This can not fail, but returns `Result` like every other command.
+    pub fn uci(&self) -> Result<(), UciError> {
         let min_hash = self.hash_option.range.start;
         let default_hash = self.hash_option.default;
         let max_hash = self.hash_option.range.end - 1;
         let mut options = format!(
             "option name Hash type spin default {default_hash} min {min_hash} max {max_hash}
 option name Ponder type check default false
-option name Threads type spin default 1 min 1 max 1"
+option name Threads type spin default 1 min 1 max {MAX_THREADS}
+option name MultiPV type spin default 1 min 1 max {MAX_MULTI_PV}
+option name Move Overhead type spin default {DEFAULT_MOVE_OVERHEAD} min 0 max {MAX_MOVE_OVERHEAD}
+option name SyzygyPath type string default <empty>
+option name SyzygyProbeLimit type spin default {DEFAULT_SYZYGY_PROBE_LIMIT} min 0 max 7
+option name EvalFile type string default <empty>
+option name OwnBook type check default false
+option name BookFile type string default <empty>
+option name BookDepth type spin default {DEFAULT_BOOK_DEPTH} min 0 max 1000
+option name BestBookMove type check default false"
         );
 
         #[cfg(feature = "spsa")]
@@ -225,10 +441,19 @@ option name Threads type spin default 1 min 1 max 1"
                 //lmr_min_depth,
                 lmr_ply_multiplier,
                 lmr_index_multiplier,
+                lmr_history_divisor,
                 //lmp_base,
+                see_capture_margin,
                 //nmp_min_depth,
                 //nmp_base_reduction,
                 //nmp_ply_divisor,
+                //razoring_max_depth,
+                razoring_margin,
+                //probcut_min_depth,
+                //probcut_depth_reduction,
+                probcut_margin,
+                //singular_min_depth,
+                singular_margin,
                 aspiration_window_start,
                 aspiration_window_growth,
                 improving_static_null_margin,
@@ -249,26 +474,41 @@ uciok",
             env!("CARGO_PKG_VERSION"),
             options
         ));
+        Ok(())
     }
 
     /// This should output `readyok`.
-    pub fn isready(&self) {
+    ///
+    /// # Errors
+    ///
+    /// This can not fail, but returns `Result` like every other command.
+    pub fn isready(&self) -> Result<(), UciError> {
         (self.out)("readyok");
+        Ok(())
     }
 
-    pub fn setoption(&mut self, args: &str) {
+    /// Changes an option, given the whole `setoption` command.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the option is not known, or its value is missing or invalid.
+    pub fn setoption(&mut self, args: &str) -> Result<(), UciError> {
         let trimmed = args.trim();
 
-        let name_index = trimmed.find("name ").expect("Did not find name");
-        let value_index = trimmed.find(" value ");
+        let name_index = trimmed.find("name ").ok_or(UciError::MissingOptionName)?;
+        if trimmed[..name_index].contains("value ") {
+            return Err(UciError::ValueBeforeName);
+        }
 
-        let (name, value) = if let Some(value_index) = value_index {
-            let name = &trimmed[(name_index + 5)..value_index];
-            let value = &trimmed[(value_index + 7)..];
-            (name, Some(value))
+        // Only the text after `name` can hold the value
+        let name_and_value = &trimmed[(name_index + 5)..];
+        let (name, value) = if let Some(value_index) = name_and_value.find(" value ") {
+            (
+                &name_and_value[..value_index],
+                Some(&name_and_value[(value_index + 7)..]),
+            )
         } else {
-            let name = &trimmed[(name_index + 5)..];
-            (name, None)
+            (name_and_value, None)
         };
 
         macro_rules! handle_option {
@@ -277,36 +517,54 @@ uciok",
                     $(
                         #[cfg(feature = "spsa")]
                         stringify!($field) => {
-                            let parsed_value = $value.expect("Missing value").parse().unwrap();
-                            assert!(TUNABLE_RANGES.$field.contains(&parsed_value));
-                            $self.tunables.$field = parsed_value;
+                            $self.tunables.$field =
+                                option_value(name, $value, TUNABLE_RANGES.$field)?;
                         }
                     )*
-                    _ => {
-                        if $value.is_none() {
-                            panic!("Unknown option name (or missing value label)");
-                        } else {
-                            panic!("Unknown option name");
-                        }
-                    }
+                    _ => return Err(UciError::UnknownOption(name.to_owned())),
                 }
             };
         }
 
-        match name.trim().to_lowercase().as_str() {
+        let name = name.trim();
+        match name.to_lowercase().as_str() {
             "hash" => {
-                let megabytes = value.expect("Missing value").parse().unwrap();
-                assert!(self.hash_option.range.contains(&megabytes));
-
+                let megabytes: u16 = option_value(name, value, self.hash_option.range.clone())?;
                 self.set_transposition_capacity(megabytes_to_capacity(megabytes.into()));
             }
             "threads" => {
-                let threads: u16 = value.expect("Missing value").parse().unwrap();
-                assert!(threads == 1, "Only supports single thread");
+                let threads = option_value(name, value, 1..=MAX_THREADS)?;
+                self.set_threads(threads);
+            }
+            "multipv" => {
+                let multi_pv = option_value(name, value, 1..=MAX_MULTI_PV)?;
+                self.set_multi_pv(multi_pv);
+            }
+            "move overhead" => {
+                let move_overhead = option_value(name, value, ..=MAX_MOVE_OVERHEAD)?;
+                self.set_move_overhead(move_overhead);
             }
             "ponder" => {
-                let ponder_allowed: bool = value.expect("Missing value").parse().unwrap();
-                self.ponder_info.ponder_allowed = ponder_allowed;
+                self.ponder_info.ponder_allowed = option_value(name, value, ..)?;
+            }
+            "syzygypath" => self.set_syzygy_path(value.unwrap_or_default().trim()),
+            "syzygyprobelimit" => {
+                self.syzygy_probe_limit = option_value(name, value, ..=7)?;
+                self.update_tablebase();
+            }
+            "ownbook" => {
+                self.own_book = option_value(name, value, ..)?;
+                self.update_book();
+            }
+            "evalfile" => self.set_eval_file(value.unwrap_or_default().trim()),
+            "bookfile" => self.set_book_file(value.unwrap_or_default().trim()),
+            "bookdepth" => {
+                self.book_depth = option_value(name, value, ..=1000)?;
+                self.update_book();
+            }
+            "bestbookmove" => {
+                self.best_book_move = option_value(name, value, ..)?;
+                self.update_book();
             }
 
             option_name => handle_option!(
@@ -324,10 +582,19 @@ uciok",
                     //lmr_min_depth,
                     lmr_ply_multiplier,
                     lmr_index_multiplier,
+                    lmr_history_divisor,
                     //lmp_base,
+                    see_capture_margin,
                     //nmp_min_depth,
                     //nmp_base_reduction,
                     //nmp_ply_divisor,
+                    //razoring_max_depth,
+                    razoring_margin,
+                    //probcut_min_depth,
+                    //probcut_depth_reduction,
+                    probcut_margin,
+                    //singular_min_depth,
+                    singular_margin,
                     aspiration_window_start,
                     aspiration_window_growth,
                     improving_static_null_margin,
@@ -339,41 +606,44 @@ uciok",
                 }
             ),
         }
+        Ok(())
     }
 
-    /// # Panics
+    /// Switches debug mode `on` or `off`. In debug mode, statistics are output after each search.
     ///
-    /// Will panic if there are invalid moves.
-    pub fn position(&mut self, args: &mut SplitWhitespace) {
-        self.moves.clear();
+    /// # Errors
+    ///
+    /// Will return `Err` if the mode is missing, or not `on` or `off`.
+    pub fn debug(&mut self, args: &mut SplitWhitespace) -> Result<(), UciError> {
+        match args.next() {
+            Some("on") => self.set_debug(true),
+            Some("off") => self.set_debug(false),
+            Some(mode) => {
+                return Err(UciError::InvalidValue {
+                    name: "debug".to_owned(),
+                    value: mode.to_owned(),
+                });
+            }
+            None => return Err(UciError::MissingValue("debug".to_owned())),
+        }
+        Ok(())
+    }
 
+    /// Sets the position to search from, which is left unchanged if the input is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the FEN is invalid, or a move is not legal.
+    pub fn position(&mut self, args: &mut SplitWhitespace) -> Result<(), UciError> {
         let mut startpos = true;
         let mut building_fen = String::new();
+        let mut uci_moves = Vec::new();
 
         while let Some(label) = args.next() {
             match label {
                 "startpos" => startpos = true,
                 "fen" => startpos = false,
-                "moves" => {
-                    for uci_move in args.by_ref() {
-                        let (from, to) = (&uci_move[0..2], &uci_move[2..4]);
-                        let (from, to) = (
-                            Square::from_notation(from).unwrap(),
-                            Square::from_notation(to).unwrap(),
-                        );
-                        let promotion = match uci_move.chars().nth(4) {
-                            None => Flag::None,
-                            Some('q') => Flag::QueenPromotion,
-                            Some('r') => Flag::RookPromotion,
-                            Some('n') => Flag::KnightPromotion,
-                            Some('b') => Flag::BishopPromotion,
-                            _ => {
-                                panic!("Invalid promotion notation in {uci_move}")
-                            }
-                        };
-                        self.moves.push((from, to, promotion));
-                    }
-                }
+                "moves" => uci_moves.extend(args.by_ref()),
                 _ => {
                     if !startpos {
                         building_fen.push_str(label);
@@ -383,27 +653,60 @@ uciok",
             }
         }
 
-        self.fen = Some(if startpos {
+        let fen = if startpos {
             Board::START_POSITION_FEN.to_owned()
         } else {
             building_fen
-        });
+        };
+        let mut board = Board::from_fen(&fen).map_err(UciError::InvalidFen)?;
+        if board.get_bit_board(Piece::WhiteKing).count() != 1
+            || board.get_bit_board(Piece::BlackKing).count() != 1
+        {
+            return Err(UciError::IllegalPosition("each side needs one king"));
+        }
+        let mut side_not_to_move = board.clone();
+        side_not_to_move.white_to_move = !side_not_to_move.white_to_move;
+        if MoveGenerator::calculate_is_in_check(&side_not_to_move) {
+            return Err(UciError::IllegalPosition(
+                "the side not to move is in check",
+            ));
+        }
+
+        let mut moves = Vec::with_capacity(uci_moves.len());
+        for uci_move in uci_moves {
+            let (from, to, promotion) = parse_move(uci_move)?;
+            let move_data = decode_legal_move(&board, from, to, promotion)
+                .ok_or_else(|| UciError::IllegalMove(uci_move.to_owned()))?;
+            board.make_move(&move_data);
+            moves.push((from, to, promotion));
+        }
+
+        self.fen = Some(fen);
+        self.moves = moves;
+        Ok(())
     }
 
-    /// # Panics
+    /// Starts searching the position, or counts its positions at a depth for `perft`.
+    /// Without parameters, the search only stops when told to.
     ///
-    /// Will panic if there are missing parameters.
-    pub fn go(&mut self, parameters: GoParameters) {
-        let mut board = Board::from_fen(self.fen.as_ref().unwrap()).unwrap();
+    /// # Errors
+    ///
+    /// Will return `Err` if no position was set, or a move in `searchmoves` is not legal.
+    pub fn go(&mut self, parameters: GoParameters) -> Result<(), UciError> {
+        let board = Board::from_fen(self.fen.as_ref().ok_or(UciError::MissingPosition)?)
+            .map_err(UciError::InvalidFen)?;
+        let mut position = board.clone();
+        for (from, to, promotion) in &self.moves {
+            position.make_move(&decode_move(&position, *from, *to, *promotion));
+        }
 
-        match parameters.search_type().unwrap() {
+        match parameters
+            .search_type()
+            .unwrap_or_else(|| SearchType::Normal(SearchTime::default()))
+        {
             SearchType::Perft(depth) => {
-                for (from, to, promotion) in &self.moves {
-                    board.make_move(&decode_move(&board, *from, *to, *promotion));
-                }
-
                 let search_start = Time::now();
-                let nodes = perft_root(&mut board, depth, self.out);
+                let nodes = perft_root(&mut position, depth, self.out);
                 let time = search_start.milliseconds();
                 let nodes_per_second = if time == 0 { 0 } else { (nodes * 1000) / time };
                 (self.out)(&format!(
@@ -411,6 +714,9 @@ uciok",
                 ));
             }
             SearchType::Normal(search_time) => {
+                // Checked now, so the search does not have to
+                search_time.search_moves(&position)?;
+
                 #[cfg(target_arch = "wasm32")]
                 {
                     self.stopped = false;
@@ -426,8 +732,17 @@ uciok",
                 }
 
                 if self.search_controller.is_none() {
-                    self.search_controller =
-                        Some(SearchController::new(self.out, self.transposition_capacity));
+                    self.search_controller = Some(SearchController::new(
+                        self.out,
+                        self.transposition_capacity,
+                        self.threads.into(),
+                    ));
+                    self.set_multi_pv(self.multi_pv);
+                    self.set_move_overhead(self.move_overhead);
+                    self.set_debug(self.debug);
+                    self.update_tablebase();
+                    self.update_network();
+                    self.update_book();
                 }
                 let search_controller = self.search_controller.as_mut().unwrap();
                 search_controller.set_position(board, self.moves.clone());
@@ -448,30 +763,128 @@ uciok",
                 );
             }
         }
+        Ok(())
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     /// Stop calculating as soon as possible.
-    pub fn stop(&self) {
+    ///
+    /// # Errors
+    ///
+    /// This can not fail, but returns `Result` like every other command.
+    pub fn stop(&self) -> Result<(), UciError> {
         self.ponder_info.is_pondering.store(false, Ordering::SeqCst);
         self.stopped.store(true, Ordering::SeqCst);
+        Ok(())
     }
 
     /// The user has played the expected move.
     /// This will be sent if the engine was told to ponder on the same move the user has played.
     /// The engine should continue searching but switch from pondering to normal search.
+    ///
+    /// # Errors
+    ///
+    /// This can not fail, but returns `Result` like every other command.
     #[cfg(not(target_arch = "wasm32"))]
-    pub fn ponderhit(&self) {
+    pub fn ponderhit(&self) -> Result<(), UciError> {
         self.ponder_info.is_pondering.store(false, Ordering::SeqCst);
+        Ok(())
     }
 
     /// This is sent to the engine when the next search (started with "position" and "go") will be from
     /// a different game. This can be a new game the engine should play or a new game it should analyse but
     /// also the next position from a testsuite with positions only.
-    pub fn ucinewgame(&mut self) {
+    ///
+    /// # Errors
+    ///
+    /// This can not fail, but returns `Result` like every other command.
+    pub fn ucinewgame(&mut self) -> Result<(), UciError> {
         // New game, so old data like the transposition table will not help
         if let Some(search_controller) = &mut self.search_controller {
             search_controller.clear_cache_for_new_game();
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GoParameters, SpinU16, UCIProcessor, UciError};
+
+    fn go_parameters(parameters: &str) -> GoParameters {
+        let mut go_parameters = GoParameters::empty();
+        go_parameters
+            .parse(&mut parameters.split_whitespace())
+            .unwrap();
+        go_parameters
+    }
+
+    #[test]
+    fn bad_input_is_rejected() {
+        let mut uci_processor = UCIProcessor::new(|_| {}, SpinU16::new(1..65, 8));
+
+        assert!(matches!(
+            uci_processor.go(go_parameters("depth 1")),
+            Err(UciError::MissingPosition)
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "startpos moves e2e4 e2e4".split_whitespace()),
+            Err(UciError::IllegalMove(_))
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "startpos moves e2".split_whitespace()),
+            Err(UciError::InvalidMove(_))
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "fen 8/8/8 w".split_whitespace()),
+            Err(UciError::InvalidFen(_))
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "fen 8/8/8/8/8/8/8/8 w - - 0 1".split_whitespace()),
+            Err(UciError::IllegalPosition(_))
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "fen 4k3/8/8/8/8/8/8/2K1K3 w - - 0 1".split_whitespace()),
+            Err(UciError::IllegalPosition(_))
+        ));
+        assert!(matches!(
+            uci_processor.position(&mut "fen 4k3/8/8/8/8/8/8/K3R3 w - - 0 1".split_whitespace()),
+            Err(UciError::IllegalPosition(_))
+        ));
+        // Nothing was set by the invalid positions
+        assert!(uci_processor.go(go_parameters("perft 1")).is_err());
+
+        uci_processor
+            .position(&mut "startpos moves e2e4 e7e5".split_whitespace())
+            .unwrap();
+        uci_processor.go(go_parameters("perft 2")).unwrap();
+        assert!(matches!(
+            uci_processor.go(go_parameters("depth 1 searchmoves e7e5")),
+            Err(UciError::IllegalMove(_))
+        ));
+
+        assert!(matches!(
+            uci_processor.setoption("setoption name Unknown value 1"),
+            Err(UciError::UnknownOption(_))
+        ));
+        assert!(matches!(
+            uci_processor.setoption("setoption name Threads value 0"),
+            Err(UciError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            uci_processor.setoption("setoption name Hash"),
+            Err(UciError::MissingValue(_))
+        ));
+        assert!(matches!(
+            uci_processor.setoption("setoption value 1"),
+            Err(UciError::MissingOptionName)
+        ));
+        assert!(matches!(
+            uci_processor.setoption("setoption value 5 name Hash"),
+            Err(UciError::ValueBeforeName)
+        ));
+        uci_processor
+            .setoption("setoption name MultiPV value 2")
+            .unwrap();
     }
 }
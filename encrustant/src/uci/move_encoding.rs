@@ -1,9 +1,14 @@
 use crate::{
     board::{Board, piece::Piece, square::Square},
-    move_generator::move_data::{Flag, Move},
+    move_generator::{
+        MoveGenerator,
+        move_data::{Flag, Move},
+    },
     search::encoded_move::EncodedMove,
 };
 
+use super::UciError;
+
 /// Encodes a move in uci notation.
 #[must_use]
 pub fn encode_move(move_data: Move) -> String {
@@ -26,6 +31,40 @@ pub fn encode_move(move_data: Move) -> String {
     encoded
 }
 
+/// Reads the squares and promotion of a move in uci notation.
+///
+/// # Errors
+///
+/// Will return `Err` if the move is not in uci notation.
+pub fn parse_move(uci_move: &str) -> Result<(Square, Square, Flag), UciError> {
+    let invalid = || UciError::InvalidMove(uci_move.to_owned());
+
+    let (from, to) = (
+        Square::from_notation(uci_move.get(0..2).ok_or_else(invalid)?).map_err(|_| invalid())?,
+        Square::from_notation(uci_move.get(2..4).ok_or_else(invalid)?).map_err(|_| invalid())?,
+    );
+    let promotion = match uci_move.get(4..) {
+        Some("") => Flag::None,
+        Some("q") => Flag::QueenPromotion,
+        Some("r") => Flag::RookPromotion,
+        Some("n") => Flag::KnightPromotion,
+        Some("b") => Flag::BishopPromotion,
+        _ => return Err(invalid()),
+    };
+    Ok((from, to, promotion))
+}
+
+/// Returns the move from `from` to `to`, if it is legal in `board`.
+#[must_use]
+pub fn decode_legal_move(board: &Board, from: Square, to: Square, promotion: Flag) -> Option<Move> {
+    board.friendly_piece_at(from)?;
+    let move_data = decode_move(board, from, to, promotion);
+
+    let mut is_legal = false;
+    MoveGenerator::new(board).generate(|legal_move| is_legal |= legal_move == move_data, false);
+    is_legal.then_some(move_data)
+}
+
 /// # Panics
 ///
 /// Will panic if there is no friendly piece at `from`.
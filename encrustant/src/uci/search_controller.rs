@@ -1,15 +1,18 @@
 use std::fmt::Write;
 use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use crate::board::Board;
 use crate::board::square::Square;
-use crate::move_generator::move_data::Flag;
+use crate::book::{Book, BookSelection};
+use crate::evaluation::{eval_data::EvalNumber, nnue::Network};
+use crate::move_generator::move_data::{Flag, Move};
 use crate::search::encoded_move::EncodedMove;
 use crate::search::pv::Pv;
 use crate::search::search_params::Tunable;
 use crate::search::time_manager::{NodeLimit, RealTime, TimeManager};
 use crate::search::{DepthSearchInfo, IMMEDIATE_CHECKMATE_SCORE, Ply, Search};
+use crate::tablebase::Tablebase;
 use crate::timer::Time;
 use crate::uci::encode_move;
 
@@ -22,11 +25,56 @@ type Bool = Arc<AtomicBool>;
 use super::go_params::SearchTime;
 use super::{PonderInfo, decode_move};
 
+/// Options set by the GUI, used by every search until they are changed.
+struct SearchOptions {
+    transposition_capacity: usize,
+    threads: usize,
+    multi_pv: usize,
+    move_overhead: u64,
+    tablebase: Option<Arc<Tablebase>>,
+    tablebase_probe_limit: usize,
+    network: Option<Arc<Network>>,
+    book: Option<Arc<Book>>,
+    book_depth: u16,
+    book_selection: BookSelection,
+    debug: bool,
+}
+impl SearchOptions {
+    const fn new(transposition_capacity: usize, threads: usize) -> Self {
+        Self {
+            transposition_capacity,
+            threads,
+            multi_pv: 1,
+            move_overhead: 0,
+            tablebase: None,
+            tablebase_probe_limit: 0,
+            network: None,
+            book: None,
+            book_depth: 0,
+            book_selection: BookSelection::Weighted,
+            debug: false,
+        }
+    }
+}
+
+/// Parameters of a single `go` command.
+struct Go {
+    search_time: SearchTime,
+    stopped: Bool,
+    ponder_info: PonderInfo,
+    mated_in: Option<Ply>,
+    #[cfg(feature = "spsa")]
+    tunables: Tunable,
+}
+
 fn output_search(out: fn(&str), info: &DepthSearchInfo, time: u64) {
-    let (pv, evaluation) = info.best;
+    let (pv, evaluation) = &info.best;
+    let evaluation = *evaluation;
     let depth = info.depth;
     let highest_depth = info.highest_depth;
     let nodes = info.node_count;
+    let tablebase_hits = info.tablebase_hits;
+    let multi_pv = info.multi_pv;
 
     let evaluation_info = if Search::score_is_checkmate(evaluation) {
         format!(
@@ -48,29 +96,114 @@ fn output_search(out: fn(&str), info: &DepthSearchInfo, time: u64) {
     };
 
     out(&format!(
-        "info depth {depth} seldepth {highest_depth} {evaluation_info} time {time} nodes {nodes} nps {nodes_per_second} pv{pv_string}"
+        "info depth {depth} seldepth {highest_depth} multipv {multi_pv} {evaluation_info} time {time} nodes {nodes} nps {nodes_per_second} tbhits {tablebase_hits} pv{pv_string}"
+    ));
+}
+
+fn output_statistics(out: fn(&str), search: &Search, helpers: &[Search]) {
+    let (probes, hits) = helpers.iter().map(Search::pawn_table_statistics).fold(
+        search.pawn_table_statistics(),
+        |(probes, hits), statistics| (probes + statistics.0, hits + statistics.1),
+    );
+    let hit_rate = (hits * 100).checked_div(probes).unwrap_or(0);
+    out(&format!(
+        "info string pawn table hits {hits} probes {probes} hit rate {hit_rate}%"
     ));
 }
 
+/// Stack size of search threads, deep enough for a search to reach the deepest ply in debug builds.
+#[cfg(not(target_arch = "wasm32"))]
+const SEARCH_THREAD_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Runs `main_search` while the helper searches of lazy SMP search the same position,
+/// then stops the helpers once it returns.
+#[cfg(not(target_arch = "wasm32"))]
+fn with_helpers<T>(helpers: &mut [Search], main_search: impl FnOnce() -> T) -> T {
+    let helpers_stopped = Arc::new(AtomicBool::new(false));
+    std::thread::scope(|scope| {
+        for helper in helpers {
+            let time_manager = TimeManager::infinite(
+                helpers_stopped.clone(),
+                Arc::new(AtomicBool::new(false)),
+                None,
+            );
+            std::thread::Builder::new()
+                .stack_size(SEARCH_THREAD_STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    let _ = helper.iterative_deepening(&time_manager, &mut |_| {});
+                })
+                .expect("Failed to start a helper thread");
+        }
+
+        let result = main_search();
+        helpers_stopped.store(true, Ordering::SeqCst);
+        result
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn with_helpers<T>(_helpers: &mut [Search], main_search: impl FnOnce() -> T) -> T {
+    main_search()
+}
+
+/// Makes sure there are `threads - 1` helpers, all ready to search the same position as `search`.
+fn set_up_helpers(
+    search: &Search,
+    helpers: &mut Vec<Search>,
+    threads: usize,
+    board: &Board,
+    moves: &[(Square, Square, Flag)],
+    search_moves: &[Move],
+) {
+    helpers.truncate(threads - 1);
+    while helpers.len() < threads - 1 {
+        helpers.push(search.new_helper(helpers.len() + 1));
+    }
+    for helper in helpers.iter_mut() {
+        helper.new_board(board.clone());
+        helper.clear_for_new_search();
+        helper.set_search_moves(search_moves);
+        for (from, to, promotion) in moves {
+            helper.make_move_repetition::<false>(&decode_move(
+                helper.board(),
+                *from,
+                *to,
+                *promotion,
+            ));
+        }
+    }
+}
+
 fn search(
     out: fn(&str),
     cached_search: &mut Option<Search>,
+    helpers: &mut Vec<Search>,
     board: &mut Option<Board>,
     moves: &mut Option<Vec<(Square, Square, Flag)>>,
-    transposition_capacity: usize,
-    search_time: SearchTime,
-    stopped: Bool,
-    ponder_info: PonderInfo,
-    mated_in: Option<Ply>,
-    #[cfg(feature = "spsa")] tunables: Tunable,
+    options: &SearchOptions,
+    go: Go,
 ) {
     let search_start = Time::now();
+    let Go {
+        search_time,
+        stopped,
+        ponder_info,
+        mated_in,
+        #[cfg(feature = "spsa")]
+        tunables,
+    } = go;
+
+    let board = board.take().unwrap();
+    let moves = moves.take().unwrap();
+    let game_ply = (board.full_move_counter.saturating_sub(1) as usize) * 2
+        + usize::from(!board.white_to_move)
+        + moves.len();
 
     let search = if cached_search.is_none() {
         // First time making search
         let search = Search::new(
-            board.take().unwrap(),
-            transposition_capacity,
+            board.clone(),
+            options.transposition_capacity,
             #[cfg(feature = "spsa")]
             tunables,
         );
@@ -79,13 +212,57 @@ fn search(
     } else {
         // Using cached search
         let search = cached_search.as_mut().unwrap();
-        search.new_board(board.take().unwrap());
+        search.new_board(board.clone());
         search.clear_for_new_search();
         search
     };
-    for (from, to, promotion) in &moves.take().unwrap() {
+    search.set_tablebase(options.tablebase.clone(), options.tablebase_probe_limit);
+    search.set_network(options.network.clone());
+    for (from, to, promotion) in &moves {
         search.make_move_repetition::<false>(&decode_move(search.board(), *from, *to, *promotion));
     }
+    let search_moves = search_time
+        .search_moves(search.board())
+        .expect("Illegal move in searchmoves");
+    search.set_search_moves(&search_moves);
+
+    // A book move is played instantly, unless the search should only stop when told to
+    if let Some(book) = &options.book
+        && search_moves.is_empty()
+        && game_ply < usize::from(options.book_depth) * 2
+        && !search_time.infinite()
+        && search_time.pondering().is_none()
+        && mated_in.is_none()
+        && let Some(book_move) = book.probe(search.board(), options.book_selection)
+    {
+        out(&format!("bestmove {}", encode_move(book_move)));
+        return;
+    }
+
+    set_up_helpers(
+        search,
+        helpers,
+        options.threads,
+        &board,
+        &moves,
+        &search_moves,
+    );
+    let helper_node_counters: Vec<Arc<AtomicU64>> =
+        helpers.iter().map(Search::node_counter).collect();
+    let helper_node_count = || {
+        helper_node_counters
+            .iter()
+            .map(|node_counter| node_counter.load(Ordering::Relaxed))
+            .sum::<u64>()
+    };
+    let helper_tablebase_hit_counters: Vec<Arc<AtomicU64>> =
+        helpers.iter().map(Search::tablebase_hit_counter).collect();
+    let helper_tablebase_hits = || {
+        helper_tablebase_hit_counters
+            .iter()
+            .map(|tablebase_hit_counter| tablebase_hit_counter.load(Ordering::Relaxed))
+            .sum::<u64>()
+    };
 
     let real_time = {
         let clock_time = if search.board().white_to_move {
@@ -101,8 +278,12 @@ fn search(
             })
             .map_or_else(|| 0, core::num::NonZero::get);
 
-            let (mut hard_time_limit, mut soft_time_limit) =
-                Search::calculate_time(clock_time, increment);
+            let (mut hard_time_limit, mut soft_time_limit) = Search::calculate_time(
+                clock_time,
+                increment,
+                search_time.moves_to_go(),
+                options.move_overhead,
+            );
             if let Some(fixed_time) = search_time.fixed_time() {
                 hard_time_limit = clock_time.min(fixed_time);
                 soft_time_limit = soft_time_limit.min(hard_time_limit);
@@ -145,24 +326,46 @@ fn search(
         }
     };
 
-    let (depth, evaluation) =
-        search.iterative_deepening(&time_manager, &mut |depth_info: DepthSearchInfo| {
-            try_update(&depth_info.best.0);
-            output_search(out, &depth_info, search_start.milliseconds());
-        });
+    let (depth, lines) = with_helpers(helpers, || {
+        search.iterative_deepening_multi_pv(
+            &time_manager,
+            options.multi_pv,
+            &mut |depth_info: DepthSearchInfo| {
+                if depth_info.multi_pv == 1 {
+                    try_update(&depth_info.best.0);
+                }
+                output_search(
+                    out,
+                    &DepthSearchInfo {
+                        node_count: depth_info.node_count + helper_node_count(),
+                        tablebase_hits: depth_info.tablebase_hits + helper_tablebase_hits(),
+                        ..depth_info
+                    },
+                    search_start.milliseconds(),
+                );
+            },
+        )
+    });
+    let evaluation = lines.first().map_or(-EvalNumber::MAX, |&(_, score)| score);
 
     try_update(&search.pv);
     output_search(
         out,
         &DepthSearchInfo {
             depth,
-            best: (search.pv, evaluation),
+            best: (search.pv.clone(), evaluation),
             highest_depth: search.highest_depth,
-            node_count: search.node_count(),
+            node_count: search.node_count() + helper_node_count(),
+            tablebase_hits: search.tablebase_hits() + helper_tablebase_hits(),
+            multi_pv: 1,
         },
         search_start.milliseconds(),
     );
 
+    if options.debug {
+        output_statistics(out, search, helpers);
+    }
+
     let mut output = format!("bestmove {}", encode_move(root_best_move.decode()),);
     if !root_best_reply.is_none() {
         write!(output, " ponder {}", encode_move(root_best_reply.decode())).unwrap();
@@ -181,79 +384,112 @@ mod search_controller {
 
     use crate::board::Board;
     use crate::board::square::Square;
+    use crate::book::{Book, BookSelection};
+    use crate::evaluation::nnue::Network;
     use crate::move_generator::move_data::Flag;
     use crate::search::search_params::Tunable;
     use crate::search::{Ply, Search};
+    use crate::tablebase::Tablebase;
+    use crate::uci::PonderInfo;
     use crate::uci::go_params::SearchTime;
-    use crate::uci::{Bool, PonderInfo};
 
-    use super::search;
+    use super::{Go, SEARCH_THREAD_STACK_SIZE, SearchOptions, search};
 
     enum SearchCommand {
         SetPosition((Board, Vec<(Square, Square, Flag)>)),
-        Search {
-            stopped: Bool,
-            search_time: SearchTime,
-            ponder_info: PonderInfo,
-            mated_in: Option<Ply>,
-            #[cfg(feature = "spsa")]
-            tunables: Tunable,
-        },
+        Search(Go),
         SetTranspositionCapacity(usize),
+        SetThreads(usize),
+        SetMultiPv(usize),
+        SetMoveOverhead(u64),
+        SetTablebase(Option<Arc<Tablebase>>, usize),
+        SetNetwork(Option<Arc<Network>>),
+        SetBook(Option<Arc<Book>>, u16, BookSelection),
+        SetDebug(bool),
         ClearCacheForNewGame,
     }
 
     pub struct SearchController(Sender<SearchCommand>);
     impl SearchController {
-        pub fn new(out: fn(&str), transposition_capacity: usize) -> Self {
+        pub fn new(out: fn(&str), transposition_capacity: usize, threads: usize) -> Self {
             let (sender, receiver) = mpsc::channel::<SearchCommand>();
-            thread::spawn(move || {
-                let mut cached_search: Option<Search> = None;
-                let mut transposition_capacity = transposition_capacity;
-                let mut board = None;
-                let mut moves = None;
-
-                for command in receiver {
-                    match command {
-                        SearchCommand::SetTranspositionCapacity(capacity) => {
-                            transposition_capacity = capacity;
-                            if let Some(search) = &mut cached_search {
-                                search.resize_transposition_table(transposition_capacity);
+            let search_thread = thread::Builder::new().stack_size(SEARCH_THREAD_STACK_SIZE);
+            search_thread
+                .spawn(move || {
+                    let mut cached_search: Option<Search> = None;
+                    let mut helpers: Vec<Search> = Vec::new();
+                    let mut options = SearchOptions::new(transposition_capacity, threads);
+                    let mut board = None;
+                    let mut moves = None;
+
+                    for command in receiver {
+                        match command {
+                            SearchCommand::SetTranspositionCapacity(capacity) => {
+                                options.transposition_capacity = capacity;
+                                if let Some(search) = &mut cached_search {
+                                    search.resize_transposition_table(capacity);
+                                }
+
+                                // Helpers are remade so that they share the new table
+                                helpers.clear();
                             }
-                        }
-                        SearchCommand::SetPosition((new_board, new_moves)) => {
-                            board = Some(new_board);
-                            moves = Some(new_moves);
-                        }
-                        SearchCommand::ClearCacheForNewGame => {
-                            if let Some(search) = &mut cached_search {
-                                search.clear_cache_for_new_game();
+                            SearchCommand::SetThreads(new_threads) => {
+                                options.threads = new_threads;
+                            }
+                            SearchCommand::SetMultiPv(new_multi_pv) => {
+                                options.multi_pv = new_multi_pv;
+                            }
+                            SearchCommand::SetMoveOverhead(new_move_overhead) => {
+                                options.move_overhead = new_move_overhead;
                             }
+                            SearchCommand::SetTablebase(new_tablebase, probe_limit) => {
+                                options.tablebase = new_tablebase;
+                                options.tablebase_probe_limit = probe_limit;
+
+                                // Helpers are remade so that they use the new tablebase
+                                helpers.clear();
+                            }
+                            SearchCommand::SetNetwork(new_network) => {
+                                options.network = new_network;
+
+                                // Helpers are remade so that they use the new network
+                                helpers.clear();
+                            }
+                            SearchCommand::SetBook(
+                                new_book,
+                                new_book_depth,
+                                new_book_selection,
+                            ) => {
+                                options.book = new_book;
+                                options.book_depth = new_book_depth;
+                                options.book_selection = new_book_selection;
+                            }
+                            SearchCommand::SetDebug(new_debug) => {
+                                options.debug = new_debug;
+                            }
+                            SearchCommand::SetPosition((new_board, new_moves)) => {
+                                board = Some(new_board);
+                                moves = Some(new_moves);
+                            }
+                            SearchCommand::ClearCacheForNewGame => {
+                                if let Some(search) = &mut cached_search {
+                                    search.clear_cache_for_new_game();
+                                }
+                                helpers.clear();
+                            }
+                            SearchCommand::Search(go) => search(
+                                out,
+                                &mut cached_search,
+                                &mut helpers,
+                                &mut board,
+                                &mut moves,
+                                &options,
+                                go,
+                            ),
                         }
-                        SearchCommand::Search {
-                            stopped,
-                            search_time,
-                            ponder_info,
-                            mated_in,
-
-                            #[cfg(feature = "spsa")]
-                            tunables,
-                        } => search(
-                            out,
-                            &mut cached_search,
-                            &mut board,
-                            &mut moves,
-                            transposition_capacity,
-                            search_time,
-                            stopped,
-                            ponder_info,
-                            mated_in,
-                            #[cfg(feature = "spsa")]
-                            tunables,
-                        ),
                     }
-                }
-            });
+                })
+                .expect("Failed to start the search thread");
             Self(sender)
         }
         pub fn search(
@@ -265,15 +501,14 @@ mod search_controller {
             #[cfg(feature = "spsa")] tunables: Tunable,
         ) {
             self.0
-                .send(SearchCommand::Search {
-                    stopped,
+                .send(SearchCommand::Search(Go {
                     search_time,
+                    stopped,
                     ponder_info,
                     mated_in,
-
                     #[cfg(feature = "spsa")]
                     tunables,
-                })
+                }))
                 .unwrap();
         }
         pub fn set_position(&self, board: Board, moves: Vec<(Square, Square, Flag)>) {
@@ -288,6 +523,33 @@ mod search_controller {
                 ))
                 .unwrap();
         }
+        pub fn set_threads(&self, threads: usize) {
+            self.0.send(SearchCommand::SetThreads(threads)).unwrap();
+        }
+        pub fn set_multi_pv(&self, multi_pv: usize) {
+            self.0.send(SearchCommand::SetMultiPv(multi_pv)).unwrap();
+        }
+        pub fn set_move_overhead(&self, move_overhead: u64) {
+            self.0
+                .send(SearchCommand::SetMoveOverhead(move_overhead))
+                .unwrap();
+        }
+        pub fn set_tablebase(&self, tablebase: Option<Arc<Tablebase>>, probe_limit: usize) {
+            self.0
+                .send(SearchCommand::SetTablebase(tablebase, probe_limit))
+                .unwrap();
+        }
+        pub fn set_network(&self, network: Option<Arc<Network>>) {
+            self.0.send(SearchCommand::SetNetwork(network)).unwrap();
+        }
+        pub fn set_book(&self, book: Option<Arc<Book>>, depth: u16, selection: BookSelection) {
+            self.0
+                .send(SearchCommand::SetBook(book, depth, selection))
+                .unwrap();
+        }
+        pub fn set_debug(&self, debug: bool) {
+            self.0.send(SearchCommand::SetDebug(debug)).unwrap();
+        }
         pub fn clear_cache_for_new_game(&self) {
             self.0.send(SearchCommand::ClearCacheForNewGame).unwrap();
         }
@@ -298,28 +560,35 @@ mod search_controller {
 mod search_controller {
     use crate::board::Board;
     use crate::board::square::Square;
+    use crate::book::{Book, BookSelection};
+    use crate::evaluation::nnue::Network;
     use crate::move_generator::move_data::Flag;
+    use std::sync::Arc;
+
     use crate::search::{Ply, Search};
+    use crate::tablebase::Tablebase;
     use crate::uci::go_params::SearchTime;
     use crate::uci::{PonderInfo, Tunable};
 
-    use super::{Bool, search};
+    use super::{Bool, Go, SearchOptions, search};
 
     pub struct SearchController {
         out: fn(&str),
         cached_search: Option<Search>,
+        helpers: Vec<Search>,
         board: Option<Board>,
         moves: Option<Vec<(Square, Square, Flag)>>,
-        transposition_capacity: usize,
+        options: SearchOptions,
     }
     impl SearchController {
-        pub fn new(out: fn(&str), transposition_capacity: usize) -> Self {
+        pub fn new(out: fn(&str), transposition_capacity: usize, _threads: usize) -> Self {
             Self {
                 out,
                 cached_search: None,
+                helpers: Vec::new(),
                 board: None,
                 moves: None,
-                transposition_capacity,
+                options: SearchOptions::new(transposition_capacity, 1),
             }
         }
         pub fn search(
@@ -333,15 +602,18 @@ mod search_controller {
             search(
                 self.out,
                 &mut self.cached_search,
+                &mut self.helpers,
                 &mut self.board,
                 &mut self.moves,
-                self.transposition_capacity,
-                search_time,
-                stopped,
-                ponder_info,
-                mated_in,
-                #[cfg(feature = "spsa")]
-                tunables,
+                &self.options,
+                Go {
+                    search_time,
+                    stopped,
+                    ponder_info,
+                    mated_in,
+                    #[cfg(feature = "spsa")]
+                    tunables,
+                },
             );
         }
         pub fn set_position(&mut self, board: Board, moves: Vec<(Square, Square, Flag)>) {
@@ -349,11 +621,35 @@ mod search_controller {
             self.moves = Some(moves);
         }
         pub fn set_transposition_capacity(&mut self, transposition_capacity: usize) {
-            self.transposition_capacity = transposition_capacity;
+            self.options.transposition_capacity = transposition_capacity;
             if let Some(search) = &mut self.cached_search {
                 search.resize_transposition_table(transposition_capacity);
             }
         }
+        pub fn set_threads(&mut self, _threads: usize) {
+            // Threads are not available, so only the main search is used
+        }
+        pub fn set_multi_pv(&mut self, multi_pv: usize) {
+            self.options.multi_pv = multi_pv;
+        }
+        pub fn set_move_overhead(&mut self, move_overhead: u64) {
+            self.options.move_overhead = move_overhead;
+        }
+        pub fn set_tablebase(&mut self, tablebase: Option<Arc<Tablebase>>, probe_limit: usize) {
+            self.options.tablebase = tablebase;
+            self.options.tablebase_probe_limit = probe_limit;
+        }
+        pub fn set_network(&mut self, network: Option<Arc<Network>>) {
+            self.options.network = network;
+        }
+        pub fn set_book(&mut self, book: Option<Arc<Book>>, depth: u16, selection: BookSelection) {
+            self.options.book = book;
+            self.options.book_depth = depth;
+            self.options.book_selection = selection;
+        }
+        pub fn set_debug(&mut self, debug: bool) {
+            self.options.debug = debug;
+        }
         pub fn clear_cache_for_new_game(&mut self) {
             if let Some(search) = &mut self.cached_search {
                 search.clear_cache_for_new_game();
@@ -363,3 +659,85 @@ mod search_controller {
 }
 
 pub use search_controller::SearchController;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex, atomic::AtomicBool};
+
+    use crate::{
+        board::Board,
+        evaluation::{endgame::KNOWN_WIN, eval_data::EvalNumber},
+        search::transposition::megabytes_to_capacity,
+        uci::{
+            PonderInfo,
+            go_params::{GoParameters, SearchType},
+        },
+    };
+
+    #[cfg(feature = "spsa")]
+    use crate::search::search_params::DEFAULT_TUNABLES;
+
+    use super::{Go, SearchOptions, search};
+
+    static OUTPUT: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    #[test]
+    fn helpers_search_with_the_main_search() {
+        let mut go_parameters = GoParameters::empty();
+        go_parameters
+            .parse(&mut "depth 6".split_whitespace())
+            .unwrap();
+        let Some(SearchType::Normal(search_time)) = go_parameters.search_type() else {
+            panic!("Not a normal search")
+        };
+
+        let mut cached_search = None;
+        let mut helpers = Vec::new();
+        search(
+            |line| OUTPUT.lock().unwrap().push(line.to_owned()),
+            &mut cached_search,
+            &mut helpers,
+            &mut Some(Board::from_fen("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap()),
+            &mut Some(Vec::new()),
+            &SearchOptions::new(megabytes_to_capacity(8), 2),
+            Go {
+                search_time,
+                stopped: Arc::new(AtomicBool::new(false)),
+                ponder_info: PonderInfo {
+                    ponder_allowed: false,
+                    is_pondering: Arc::new(AtomicBool::new(false)),
+                },
+                mated_in: None,
+                #[cfg(feature = "spsa")]
+                tunables: DEFAULT_TUNABLES,
+            },
+        );
+
+        // The helper searches without a limit, so the search only returned once it was stopped
+        let search = cached_search.unwrap();
+        let [helper] = &helpers[..] else {
+            panic!("Expected one helper")
+        };
+        assert!(helper.node_count() > 0);
+
+        let output = OUTPUT.lock().unwrap();
+        let [.., info, best_move] = &output[..] else {
+            panic!("Expected a last info line and the best move")
+        };
+
+        let value = |name| {
+            info.split_whitespace()
+                .skip_while(|&word| word != name)
+                .nth(1)
+                .unwrap()
+        };
+
+        // Taking the queen leaves a won rook ending
+        assert!(best_move.starts_with("bestmove d1d5"));
+        assert!(value("cp").parse::<EvalNumber>().unwrap() > KNOWN_WIN);
+
+        // Both threads count towards the nodes
+        let nodes: u64 = value("nodes").parse().unwrap();
+        assert_eq!(nodes, search.node_count() + helper.node_count());
+    }
+}
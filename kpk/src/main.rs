@@ -0,0 +1,195 @@
+#![deny(clippy::all)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+
+use encrustant::{
+    board::{bit_board::BitBoard, square::Square},
+    evaluation::kpk::{POSITION_COUNT, decode, index},
+    move_generator::{
+        MoveGenerator,
+        slider_lookup::{
+            get_bishop_moves, get_rook_moves, relevant_bishop_blockers, relevant_rook_blockers,
+        },
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Result {
+    Invalid,
+    Unknown,
+    Draw,
+    Win,
+}
+
+fn pawn_attacks(pawn: Square) -> BitBoard {
+    let mut attacks = BitBoard::EMPTY;
+    if pawn.file() > 0 {
+        attacks.set(&pawn.up(1).left(1));
+    }
+    if pawn.file() < 7 {
+        attacks.set(&pawn.up(1).right(1));
+    }
+    attacks
+}
+
+fn is_valid(white_to_move: bool, white_king: Square, black_king: Square, pawn: Square) -> bool {
+    white_king != black_king
+        && white_king != pawn
+        && black_king != pawn
+        && !MoveGenerator::king_attack_bit_board(white_king).get(&black_king)
+        && !(white_to_move && pawn_attacks(pawn).get(&black_king))
+}
+
+/// Returns whether promoting to a queen or rook on `promotion` wins, with black to move.
+fn promotion_wins(white_king: Square, black_king: Square, promotion: Square) -> bool {
+    let white_king_attacks = MoveGenerator::king_attack_bit_board(white_king);
+    let black_king_moves = MoveGenerator::king_attack_bit_board(black_king);
+
+    // The black king can capture an undefended piece
+    if black_king_moves.get(&promotion) && !white_king_attacks.get(&promotion) {
+        return false;
+    }
+
+    // The black king does not block attacks on the squares behind it
+    let occupied = BitBoard::from_square(&white_king) | BitBoard::from_square(&promotion);
+    let rook_attacks = get_rook_moves(promotion, occupied & relevant_rook_blockers(promotion));
+    let queen_attacks = rook_attacks
+        | get_bishop_moves(promotion, occupied & relevant_bishop_blockers(promotion));
+
+    [queen_attacks, rook_attacks].into_iter().any(|attacks| {
+        let escapes = black_king_moves & !white_king_attacks & !attacks;
+        escapes.is_not_empty() || attacks.get(&black_king)
+    })
+}
+
+fn white_to_move(
+    results: &[Result],
+    white_king: Square,
+    black_king: Square,
+    pawn: Square,
+) -> Result {
+    let black_king_attacks = MoveGenerator::king_attack_bit_board(black_king);
+    let mut king_moves = MoveGenerator::king_attack_bit_board(white_king) & !black_king_attacks;
+    king_moves.unset(&pawn);
+
+    let mut children = Vec::new();
+    while king_moves.is_not_empty() {
+        children.push(results[index(false, king_moves.pop_square(), black_king, pawn)]);
+    }
+
+    let push = pawn.up(1);
+    if push != white_king && push != black_king {
+        if push.rank() == 7 {
+            if promotion_wins(white_king, black_king, push) {
+                return Result::Win;
+            }
+        } else {
+            children.push(results[index(false, white_king, black_king, push)]);
+
+            let double_push = push.up(1);
+            if pawn.rank() == 1 && double_push != white_king && double_push != black_king {
+                children.push(results[index(false, white_king, black_king, double_push)]);
+            }
+        }
+    }
+
+    if children.contains(&Result::Win) {
+        Result::Win
+    } else if children.iter().all(|&result| result == Result::Draw) {
+        Result::Draw
+    } else {
+        Result::Unknown
+    }
+}
+
+fn black_to_move(
+    results: &[Result],
+    white_king: Square,
+    black_king: Square,
+    pawn: Square,
+) -> Result {
+    let white_king_attacks = MoveGenerator::king_attack_bit_board(white_king);
+    let mut king_moves = MoveGenerator::king_attack_bit_board(black_king)
+        & !white_king_attacks
+        & !pawn_attacks(pawn);
+
+    if king_moves.is_empty() {
+        return if pawn_attacks(pawn).get(&black_king) {
+            Result::Win
+        } else {
+            Result::Draw
+        };
+    }
+
+    let mut children = Vec::new();
+    while king_moves.is_not_empty() {
+        let king_move = king_moves.pop_square();
+        if king_move == pawn {
+            // Only kings are left
+            return Result::Draw;
+        }
+        children.push(results[index(true, white_king, king_move, pawn)]);
+    }
+
+    if children.contains(&Result::Draw) {
+        Result::Draw
+    } else if children.iter().all(|&result| result == Result::Win) {
+        Result::Win
+    } else {
+        Result::Unknown
+    }
+}
+
+fn main() {
+    let mut results: Vec<Result> = (0..POSITION_COUNT)
+        .map(|position| {
+            let (white_to_move, white_king, black_king, pawn) = decode(position);
+            if is_valid(white_to_move, white_king, black_king, pawn) {
+                Result::Unknown
+            } else {
+                Result::Invalid
+            }
+        })
+        .collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for position in 0..POSITION_COUNT {
+            if results[position] != Result::Unknown {
+                continue;
+            }
+
+            let (is_white_to_move, white_king, black_king, pawn) = decode(position);
+            let result = if is_white_to_move {
+                white_to_move(&results, white_king, black_king, pawn)
+            } else {
+                black_to_move(&results, white_king, black_king, pawn)
+            };
+            if result != Result::Unknown {
+                results[position] = result;
+                changed = true;
+            }
+        }
+    }
+
+    // Positions that can never be resolved are draws
+    let mut bitbase = vec![0_u64; POSITION_COUNT / 64];
+    for (position, &result) in results.iter().enumerate() {
+        if result == Result::Win {
+            bitbase[position / 64] |= 1 << (position % 64);
+        }
+    }
+
+    println!("//! Generated by the `kpk` crate.");
+    println!();
+    println!("/// Bit set for every position in which white wins, indexed by [`super::kpk::index`].");
+    println!("#[rustfmt::skip]");
+    println!("#[allow(clippy::unreadable_literal)]");
+    println!("pub static KPK_BITBASE: [u64; {}] = [", bitbase.len());
+    for chunk in bitbase.chunks(4) {
+        let line: Vec<String> = chunk.iter().map(|bits| format!("0x{bits:016x},")).collect();
+        println!("    {}", line.join(" "));
+    }
+    println!("];");
+}
@@ -3,7 +3,8 @@
 
 use encrustant::board::Board;
 use encrustant::evaluation::Eval;
-use encrustant::evaluation::eval_data::PieceSquareTable;
+use encrustant::evaluation::endgame::{self, Endgame};
+use encrustant::evaluation::eval_data::{self, EvalTerms, PieceSquareTable};
 use rayon::prelude::*;
 use std::io::BufRead;
 use std::time::Instant;
@@ -30,6 +31,11 @@ fn parse_data_set() -> Vec<(Board, f64)> {
         };
 
         let board = Board::from_fen(fen).unwrap();
+
+        // Known endgames are scored by their lookup instead of the parameters being tuned
+        if matches!(endgame::probe(&board), Some(Endgame::Score(_))) {
+            continue;
+        }
         parsed.push((board, result));
     }
     parsed.shrink_to_fit();
@@ -42,6 +48,7 @@ fn mean_square_error(
     k: f64,
     middle_game_piece_square_tables: &PieceSquareTable,
     end_game_piece_square_tables: &PieceSquareTable,
+    terms: &[EvalTerms; 2],
     phases: &[i32; 5],
 ) -> f64 {
     let total_square_error: f64 = data_set
@@ -51,6 +58,8 @@ fn mean_square_error(
                 Eval::evaluate_with_parameters(
                     middle_game_piece_square_tables,
                     end_game_piece_square_tables,
+                    &terms[0],
+                    &terms[1],
                     phases,
                     board,
                 ) * if board.white_to_move { 1 } else { -1 },
@@ -85,14 +94,24 @@ fn pretty_piece_square_tables(piece_square_tables: PieceSquareTable) -> String {
     output
 }
 
+/// Parameters the tuner adjusts.
+#[derive(Clone, Copy)]
+struct Parameters {
+    psqt: [PieceSquareTable; 2],
+    terms: [EvalTerms; 2],
+    phases: [i32; 5],
+}
+
 fn tune(
     data_set: &[(Board, f64)],
     k: f64,
     middle_game_piece_square_tables: &PieceSquareTable,
     end_game_piece_square_tables: &PieceSquareTable,
+    terms: &[EvalTerms; 2],
     phases: &[i32; 5],
 ) {
     const PSQT_ADJUSTMENT_VALUE: i16 = 1;
+    const TERM_ADJUSTMENT_VALUE: i16 = 1;
     const PHASE_ADJUSTMENT_VALUE: i32 = 1;
 
     let mut best_error = mean_square_error(
@@ -100,19 +119,24 @@ fn tune(
         k,
         middle_game_piece_square_tables,
         end_game_piece_square_tables,
+        terms,
         phases,
     );
     println!("Currently {best_error}");
 
-    let log_params = |psqt_1, psqt_2, new_phases| {
+    let log_params = |psqt_1, psqt_2, new_terms: [EvalTerms; 2], new_phases| {
         std::fs::write(
             "tuned.rs",
             format!(
                 "const MIDDLE_GAME_PIECE_SQUARE_TABLES: PieceSquareTable = {};
 const END_GAME_PIECE_SQUARE_TABLES: PieceSquareTable = {};
+const MIDDLE_GAME_TERMS: EvalTerms = {:?};
+const END_GAME_TERMS: EvalTerms = {:?};
 const PHASES: [i32; 5] = {:#?};",
                 pretty_piece_square_tables(psqt_1),
                 pretty_piece_square_tables(psqt_2),
+                new_terms[0],
+                new_terms[1],
                 new_phases
             ),
         )
@@ -121,73 +145,69 @@ const PHASES: [i32; 5] = {:#?};",
     log_params(
         *middle_game_piece_square_tables,
         *end_game_piece_square_tables,
+        *terms,
         *phases,
     );
 
-    let mut best_psqt = [
-        *middle_game_piece_square_tables,
-        *end_game_piece_square_tables,
-    ];
-    let mut best_phases = *phases;
+    let mut best = Parameters {
+        psqt: [
+            *middle_game_piece_square_tables,
+            *end_game_piece_square_tables,
+        ],
+        terms: *terms,
+        phases: *phases,
+    };
     let mut improved = true;
 
+    // Tries moving one parameter up, then down, keeping the first step that lowers the error
+    let try_step =
+        |best: &mut Parameters, best_error: &mut f64, step: &dyn Fn(&mut Parameters, i16)| {
+            for (direction, sign) in [(1, '+'), (-1, '-')] {
+                let mut new = *best;
+                step(&mut new, direction);
+
+                let new_error = mean_square_error(
+                    data_set,
+                    k,
+                    &new.psqt[0],
+                    &new.psqt[1],
+                    &new.terms,
+                    &new.phases,
+                );
+                if new_error < *best_error {
+                    println!("{new_error} Found better params {sign}");
+                    *best_error = new_error;
+                    *best = new;
+                    return true;
+                }
+            }
+            false
+        };
+
     while improved {
         improved = false;
 
         for table_number in 0..2 {
             for index in 0..384 {
-                let mut new_psqts: [PieceSquareTable; 2] = best_psqt;
-                new_psqts[table_number][index] += PSQT_ADJUSTMENT_VALUE;
-
-                let mut new_error =
-                    mean_square_error(data_set, k, &new_psqts[0], &new_psqts[1], &best_phases);
-
-                if new_error < best_error {
-                    println!("{new_error} Found better params +");
-                } else {
-                    new_psqts[table_number][index] -= PSQT_ADJUSTMENT_VALUE * 2;
-                    new_error =
-                        mean_square_error(data_set, k, &new_psqts[0], &new_psqts[1], &best_phases);
-
-                    if new_error < best_error {
-                        println!("{new_error} Found better params -");
-                    } else {
-                        continue;
-                    }
-                }
-
-                improved = true;
-                best_error = new_error;
-                best_psqt = new_psqts;
+                improved |= try_step(&mut best, &mut best_error, &|new, direction| {
+                    new.psqt[table_number][index] += PSQT_ADJUSTMENT_VALUE * direction;
+                });
             }
         }
-        for index in 0..5 {
-            let mut new_phases = best_phases;
-            new_phases[index] += PHASE_ADJUSTMENT_VALUE;
-
-            let mut new_error =
-                mean_square_error(data_set, k, &best_psqt[0], &best_psqt[1], &new_phases);
-
-            if new_error < best_error {
-                println!("{new_error} Found better params +");
-            } else {
-                new_phases[index] -= PHASE_ADJUSTMENT_VALUE * 2;
-                new_error =
-                    mean_square_error(data_set, k, &best_psqt[0], &best_psqt[1], &new_phases);
-
-                if new_error < best_error {
-                    println!("{new_error} Found better params -");
-                } else {
-                    continue;
-                }
+        for table_number in 0..2 {
+            for index in 0..eval_data::TERM_COUNT {
+                improved |= try_step(&mut best, &mut best_error, &|new, direction| {
+                    new.terms[table_number][index] += TERM_ADJUSTMENT_VALUE * direction;
+                });
             }
-
-            improved = true;
-            best_error = new_error;
-            best_phases = new_phases;
+        }
+        for index in 0..5 {
+            improved |= try_step(&mut best, &mut best_error, &|new, direction| {
+                new.phases[index] += PHASE_ADJUSTMENT_VALUE * i32::from(direction);
+            });
         }
 
-        log_params(best_psqt[0], best_psqt[1], best_phases);
+        log_params(best.psqt[0], best.psqt[1], best.terms, best.phases);
         println!("Finished one iteration");
     }
 }
@@ -196,6 +216,7 @@ fn find_k(
     data_set: &[(Board, f64)],
     middle_game_piece_square_tables: &PieceSquareTable,
     end_game_piece_square_tables: &PieceSquareTable,
+    terms: &[EvalTerms; 2],
     phases: &[i32; 5],
 ) -> f64 {
     let mut min = -10.0;
@@ -214,6 +235,7 @@ fn find_k(
                 min,
                 middle_game_piece_square_tables,
                 end_game_piece_square_tables,
+                terms,
                 phases,
             );
             if error < best_error {
@@ -357,6 +379,8 @@ fn main() {
       0,   0,   0,   0,   0,   0,   0,   0,
     ];
 
+    let terms: [EvalTerms; 2] = [eval_data::MIDDLE_GAME_TERMS, eval_data::END_GAME_TERMS];
+
     let phases: [i32; 5] = [
         000, // Pawn
         100, // Knight
@@ -377,6 +401,7 @@ fn main() {
         &data_set,
         &middle_game_piece_square_tables,
         &end_game_piece_square_tables,
+        &terms,
         &phases,
     );
     println!(
@@ -390,6 +415,7 @@ fn main() {
         k,
         &middle_game_piece_square_tables,
         &end_game_piece_square_tables,
+        &terms,
         &phases,
     );
     println!(